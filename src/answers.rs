@@ -0,0 +1,295 @@
+use {
+    std::{fs::{read_to_string, write}, io, path::{Path, PathBuf}, str::FromStr},
+    crate::{config, submit::SubmissionOutcome},
+};
+
+const ANSWERS_FILE: &str = "answers.txt";
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    pub fn for_outcome(outcome: &SubmissionOutcome) -> Option<Self> {
+        Some(match outcome {
+            SubmissionOutcome::Correct => Self::Correct,
+            SubmissionOutcome::TooHigh => Self::TooHigh,
+            SubmissionOutcome::TooLow => Self::TooLow,
+            SubmissionOutcome::Wrong => Self::Wrong,
+            // nothing was learned about the answer
+            SubmissionOutcome::RateLimited(_) | SubmissionOutcome::AlreadySolved => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::Wrong => "wrong",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "correct" => Self::Correct,
+            "too_high" => Self::TooHigh,
+            "too_low" => Self::TooLow,
+            "wrong" => Self::Wrong,
+            other => return Err(format!("unknown verdict \"{}\", expected one of: correct, too_high, too_low, wrong", other)),
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct AnswerRecord {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum AnswerCheck {
+    Unknown,
+    Accepted,
+    // a different answer was already accepted
+    DiffersFromAccepted(String),
+    Rejected(Verdict),
+    // not greater than an answer that was too low
+    BelowLowerBound(i128),
+    // not less than an answer that was too high
+    AboveUpperBound(i128),
+}
+
+impl AnswerCheck {
+    pub fn is_known_wrong(&self) -> bool {
+        !matches!(self, Self::Unknown | Self::Accepted)
+    }
+}
+
+impl std::fmt::Display for AnswerCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "answer was not submitted before"),
+            Self::Accepted => write!(f, "answer was accepted before"),
+            Self::DiffersFromAccepted(accepted) => write!(f, "answer differs from accepted answer ({})", accepted),
+            Self::Rejected(verdict) => write!(f, "answer was already rejected as {}", verdict),
+            Self::BelowLowerBound(bound) => write!(f, "answer is too low, it should be greater than {}", bound),
+            Self::AboveUpperBound(bound) => write!(f, "answer is too high, it should be less than {}", bound),
+        }
+    }
+}
+
+pub struct AnswerStore {
+    path: PathBuf,
+    records: Vec<AnswerRecord>,
+}
+
+impl AnswerStore {
+    pub fn load_default() -> io::Result<Self> {
+        Self::load(&config::get().year_cache_dir().join(ANSWERS_FILE))
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let records = if path.exists() {
+            parse_records(&read_to_string(path)?)?
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self {
+            path: path.to_owned(),
+            records,
+        })
+    }
+
+    pub fn records(&self) -> &[AnswerRecord] {
+        &self.records
+    }
+
+    pub fn record(&mut self, day: u32, part: u32, answer: &str, verdict: Verdict) -> io::Result<()> {
+        self.records.retain(|record| record.day != day || record.part != part || record.answer != answer);
+        self.records.push(AnswerRecord {
+            day,
+            part,
+            answer: answer.to_owned(),
+            verdict,
+        });
+        self.records.sort_by_key(|record| (record.day, record.part));
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        write(&self.path, encode_records(&self.records))
+    }
+
+    pub fn accepted(&self, day: u32, part: u32) -> Option<&str> {
+        self.records_for(day, part)
+            .find(|record| record.verdict == Verdict::Correct)
+            .map(|record| record.answer.as_str())
+    }
+
+    // exclusive bounds derived from answers that were too low or too high
+    pub fn bounds(&self, day: u32, part: u32) -> (Option<i128>, Option<i128>) {
+        let numeric = |verdict: Verdict| self.records_for(day, part)
+            .filter(move |record| record.verdict == verdict)
+            .filter_map(|record| record.answer.parse::<i128>().ok());
+
+        (numeric(Verdict::TooLow).max(), numeric(Verdict::TooHigh).min())
+    }
+
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> AnswerCheck {
+        if let Some(accepted) = self.accepted(day, part) {
+            return if accepted == answer {
+                AnswerCheck::Accepted
+            } else {
+                AnswerCheck::DiffersFromAccepted(accepted.to_owned())
+            };
+        }
+
+        if let Some(record) = self.records_for(day, part).find(|record| record.answer == answer) {
+            return AnswerCheck::Rejected(record.verdict);
+        }
+
+        if let Ok(answer) = answer.parse::<i128>() {
+            let (lower, upper) = self.bounds(day, part);
+            if let Some(lower) = lower.filter(|lower| answer <= *lower) {
+                return AnswerCheck::BelowLowerBound(lower);
+            }
+            if let Some(upper) = upper.filter(|upper| answer >= *upper) {
+                return AnswerCheck::AboveUpperBound(upper);
+            }
+        }
+
+        AnswerCheck::Unknown
+    }
+
+    fn records_for(&self, day: u32, part: u32) -> impl Iterator<Item = &AnswerRecord> {
+        self.records.iter().filter(move |record| record.day == day && record.part == part)
+    }
+}
+
+// one record per line: "<day> <part> <verdict> <answer>"
+fn parse_records(s: &str) -> io::Result<Vec<AnswerRecord>> {
+    s.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let invalid = || io::Error::new(io::ErrorKind::InvalidData, format!("invalid answer record: {}", line));
+
+            let spl = line.splitn(4, ' ').collect::<Vec<_>>();
+            if spl.len() != 4 {
+                return Err(invalid());
+            }
+
+            Ok(AnswerRecord {
+                day: spl[0].parse().map_err(|_| invalid())?,
+                part: spl[1].parse().map_err(|_| invalid())?,
+                verdict: spl[2].parse().map_err(|_| invalid())?,
+                answer: spl[3].to_owned(),
+            })
+        })
+        .collect()
+}
+
+fn encode_records(records: &[AnswerRecord]) -> String {
+    records.iter()
+        .map(|record| format!("{} {} {} {}\n", record.day, record.part, record.verdict, record.answer))
+        .collect()
+}
+
+// prints a warning if answer is known to be wrong.
+pub fn warn_if_known_wrong(day: u32, part: u32, answer: &str) {
+    let store = match AnswerStore::load_default() {
+        Ok(v) => v,
+        Err(err) => {
+            eprintln!("warning: failed to load answer history: {}", err);
+            return;
+        }
+    };
+
+    let check = store.check(day, part, answer);
+    if check.is_known_wrong() {
+        eprintln!("warning: day {} part {}: {}", day, part, check);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(records: &str) -> AnswerStore {
+        AnswerStore {
+            path: PathBuf::new(),
+            records: parse_records(records).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_records_roundtrip() {
+        let records = "6 2 too_low 1605\n6 2 too_high 2000\n7 1 correct 3749\n";
+        assert_eq!(encode_records(&parse_records(records).unwrap()), records);
+    }
+
+    #[test]
+    fn test_invalid_record() {
+        assert!(parse_records("6 2 maybe 1605").is_err());
+        assert!(parse_records("6 2 correct").is_err());
+    }
+
+    #[test]
+    fn test_bounds() {
+        let store = store("6 2 too_low 1500\n6 2 too_low 1605\n6 2 too_high 2000\n6 2 too_high 2100\n6 2 wrong 1800\n");
+        assert_eq!(store.bounds(6, 2), (Some(1605), Some(2000)));
+        assert_eq!(store.bounds(6, 1), (None, None));
+    }
+
+    #[test]
+    fn test_tracked_history() {
+        // answers rejected before history was recorded
+        assert_eq!(AnswerStore::load_default().unwrap().bounds(6, 2), (Some(1605), Some(2000)));
+    }
+
+    #[test]
+    fn test_check() {
+        let store = store("6 2 too_low 1605\n6 2 too_high 2000\n6 2 wrong 1800\n");
+        assert_eq!(store.check(6, 2, "1600"), AnswerCheck::BelowLowerBound(1605));
+        assert_eq!(store.check(6, 2, "1605"), AnswerCheck::Rejected(Verdict::TooLow));
+        assert_eq!(store.check(6, 2, "2001"), AnswerCheck::AboveUpperBound(2000));
+        assert_eq!(store.check(6, 2, "1800"), AnswerCheck::Rejected(Verdict::Wrong));
+        assert_eq!(store.check(6, 2, "1700"), AnswerCheck::Unknown);
+        assert_eq!(store.check(6, 1, "1"), AnswerCheck::Unknown);
+    }
+
+    #[test]
+    fn test_check_accepted() {
+        let store = store("7 1 too_low 10\n7 1 correct 3749\n");
+        assert_eq!(store.check(7, 1, "3749"), AnswerCheck::Accepted);
+        assert_eq!(store.check(7, 1, "3750"), AnswerCheck::DiffersFromAccepted("3749".to_owned()));
+        assert!(!store.check(7, 1, "3749").is_known_wrong());
+        assert!(store.check(7, 1, "5").is_known_wrong());
+    }
+
+    #[test]
+    fn test_record() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.txt", std::process::id()));
+        let mut store = AnswerStore::load(&path).unwrap();
+        store.record(6, 2, "1605", Verdict::TooLow).unwrap();
+        store.record(6, 2, "1700", Verdict::Correct).unwrap();
+
+        let store = AnswerStore::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(store.accepted(6, 2), Some("1700"));
+        assert_eq!(store.records().len(), 2);
+    }
+}
@@ -0,0 +1,307 @@
+use {
+    std::{fs::{read_to_string, write}, io, path::{Path, PathBuf}, time::Duration},
+    crate::{
+        progress::NoProgress,
+        registry::{RegistryError, find_part},
+        utils::{DownloadError, download_input},
+    },
+};
+
+pub struct BenchOptions {
+    pub iterations: u32,
+    pub warmup: u32,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: Option<PathBuf>,
+    // allowed slowdown compared to baseline, in percent
+    pub threshold: f64,
+}
+
+#[derive(Debug)]
+pub enum BenchError {
+    Registry(RegistryError),
+    Download(DownloadError),
+    Baseline(io::Error),
+    SlowerThanBaseline,
+}
+
+impl std::fmt::Display for BenchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Registry(err) => err.fmt(f),
+            Self::Download(err) => err.fmt(f),
+            Self::Baseline(err) => write!(f, "failed to access baseline file: {}", err),
+            Self::SlowerThanBaseline => write!(f, "solution is slower than baseline"),
+        }
+    }
+}
+
+impl std::error::Error for BenchError {}
+
+impl From<RegistryError> for BenchError {
+    fn from(err: RegistryError) -> Self {
+        Self::Registry(err)
+    }
+}
+
+impl From<DownloadError> for BenchError {
+    fn from(err: DownloadError) -> Self {
+        Self::Download(err)
+    }
+}
+
+impl From<io::Error> for BenchError {
+    fn from(err: io::Error) -> Self {
+        Self::Baseline(err)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut samples = samples.to_vec();
+        samples.sort();
+
+        Self {
+            min: samples[0],
+            median: percentile(&samples, 50),
+            p95: percentile(&samples, 95),
+        }
+    }
+}
+
+// nearest-rank percentile of sorted samples
+fn percentile(sorted_samples: &[Duration], percentile: usize) -> Duration {
+    let rank = (sorted_samples.len() * percentile).div_ceil(100);
+    sorted_samples[rank.max(1) - 1]
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct BaselineEntry {
+    pub day: u32,
+    pub part: u32,
+    pub parse_median: Duration,
+    pub solve_median: Duration,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Baseline {
+    entries: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        Self::parse(&read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        write(path, self.encode_to_string())
+    }
+
+    // one entry per line: "<day> <part> <parse median ns> <solve median ns>"
+    fn parse(s: &str) -> io::Result<Self> {
+        let entries = s.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let spl = line.split_whitespace().map(|v| v.parse::<u64>()).collect::<Result<Vec<_>, _>>();
+                match spl.as_deref() {
+                    Ok([day, part, parse_median, solve_median]) => Ok(BaselineEntry {
+                        day: *day as u32,
+                        part: *part as u32,
+                        parse_median: Duration::from_nanos(*parse_median),
+                        solve_median: Duration::from_nanos(*solve_median),
+                    }),
+                    _ => Err(io::Error::new(io::ErrorKind::InvalidData, format!("invalid baseline entry: {}", line))),
+                }
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            entries,
+        })
+    }
+
+    fn encode_to_string(&self) -> String {
+        self.entries.iter()
+            .map(|entry| format!(
+                "{} {} {} {}\n",
+                entry.day,
+                entry.part,
+                entry.parse_median.as_nanos(),
+                entry.solve_median.as_nanos()
+            ))
+            .collect()
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&BaselineEntry> {
+        self.entries.iter().find(|entry| entry.day == day && entry.part == part)
+    }
+
+    pub fn set(&mut self, entry: BaselineEntry) {
+        self.entries.retain(|other| other.day != entry.day || other.part != entry.part);
+        self.entries.push(entry);
+        self.entries.sort_by_key(|entry| (entry.day, entry.part));
+    }
+}
+
+pub fn bench(day: u32, part: u32, options: &BenchOptions) -> Result<(), BenchError> {
+    let puzzle_part = find_part(day, part)?;
+    let input = download_input(day)?;
+
+    for _ in 0..options.warmup {
+        (puzzle_part.solve)(&input, &NoProgress);
+    }
+
+    let mut parse_samples = Vec::new();
+    let mut solve_samples = Vec::new();
+    for _ in 0..options.iterations.max(1) {
+        let result = (puzzle_part.solve)(&input, &NoProgress);
+        parse_samples.push(result.parse_time);
+        solve_samples.push(result.solve_time);
+    }
+
+    let parse = Statistics::from_samples(&parse_samples);
+    let solve = Statistics::from_samples(&solve_samples);
+
+    println!("day {} part {}: {} iterations after {} warmup", day, part, parse_samples.len(), options.warmup);
+    println!("{:>6} {:>14} {:>14} {:>14}", "", "min", "median", "p95");
+    for (name, statistics) in [("parse", &parse), ("solve", &solve)] {
+        println!(
+            "{:>6} {:>14} {:>14} {:>14}",
+            name,
+            format!("{:.3?}", statistics.min),
+            format!("{:.3?}", statistics.median),
+            format!("{:.3?}", statistics.p95),
+        );
+    }
+
+    let entry = BaselineEntry {
+        day,
+        part,
+        parse_median: parse.median,
+        solve_median: solve.median,
+    };
+
+    let mut slower_than_baseline = false;
+    if let Some(path) = &options.baseline {
+        match Baseline::load(path)?.get(day, part) {
+            Some(baseline) => {
+                for (name, current, baseline) in [
+                    ("parse", entry.parse_median, baseline.parse_median),
+                    ("solve", entry.solve_median, baseline.solve_median),
+                ] {
+                    let change = relative_change(baseline, current);
+                    println!("{} median changed by {:+.1}% compared to baseline ({:.3?})", name, change, baseline);
+                    if change > options.threshold {
+                        eprintln!("{} is slower than baseline by more than {:.1}%", name, options.threshold);
+                        slower_than_baseline = true;
+                    }
+                }
+            },
+            None => eprintln!("no baseline recorded for day {} part {}", day, part),
+        }
+    }
+
+    if let Some(path) = &options.save_baseline {
+        let mut baseline = Baseline::load(path)?;
+        baseline.set(entry);
+        baseline.save(path)?;
+    }
+
+    if slower_than_baseline {
+        return Err(BenchError::SlowerThanBaseline);
+    }
+
+    Ok(())
+}
+
+// in percent, positive means slowdown
+fn relative_change(baseline: Duration, current: Duration) -> f64 {
+    if baseline.is_zero() {
+        return 0.0;
+    }
+
+    (current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn test_statistics() {
+        assert_eq!(
+            Statistics::from_samples(&millis(&[5, 1, 4, 2, 3, 10, 6, 7, 9, 8])),
+            Statistics {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(5),
+                p95: Duration::from_millis(10),
+            }
+        );
+    }
+
+    #[test]
+    fn test_statistics_single_sample() {
+        let statistics = Statistics::from_samples(&millis(&[3]));
+        assert_eq!(statistics.min, Duration::from_millis(3));
+        assert_eq!(statistics.median, Duration::from_millis(3));
+        assert_eq!(statistics.p95, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.set(BaselineEntry {
+            day: 6,
+            part: 2,
+            parse_median: Duration::from_nanos(1200),
+            solve_median: Duration::from_millis(900),
+        });
+        baseline.set(BaselineEntry {
+            day: 1,
+            part: 1,
+            parse_median: Duration::from_nanos(300),
+            solve_median: Duration::from_nanos(400),
+        });
+
+        let encoded = baseline.encode_to_string();
+        assert_eq!(encoded, "1 1 300 400\n6 2 1200 900000000\n");
+        assert_eq!(Baseline::parse(&encoded).unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_baseline_set_replaces_entry() {
+        let mut baseline = Baseline::parse("6 2 1200 900").unwrap();
+        baseline.set(BaselineEntry {
+            day: 6,
+            part: 2,
+            parse_median: Duration::from_nanos(1000),
+            solve_median: Duration::from_nanos(800),
+        });
+        assert_eq!(baseline.encode_to_string(), "6 2 1000 800\n");
+    }
+
+    #[test]
+    fn test_invalid_baseline() {
+        assert!(Baseline::parse("6 2 fast").is_err());
+    }
+
+    #[test]
+    fn test_relative_change() {
+        assert_eq!(relative_change(Duration::from_millis(100), Duration::from_millis(150)).round(), 50.0);
+        assert_eq!(relative_change(Duration::from_millis(100), Duration::from_millis(50)).round(), -50.0);
+    }
+}
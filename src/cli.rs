@@ -0,0 +1,150 @@
+use {
+    std::path::PathBuf,
+    clap::{Parser, Subcommand},
+    crate::{answers::Verdict, progress::ProgressMode, runner::OutputFormat},
+};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Command,
+    /// Never access advent of code website, fail if input is not cached (also enabled by AOC_OFFLINE=1)
+    #[arg(long, global = true)]
+    pub offline: bool,
+    /// Config file to use instead of aoc.toml (also set by AOC_CONFIG)
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+    /// Advent of code year (also set by AOC_YEAR)
+    #[arg(long, global = true)]
+    pub year: Option<u32>,
+    /// Advent of code website url (also set by AOC_BASE_URL)
+    #[arg(long, global = true)]
+    pub base_url: Option<String>,
+    /// Directory for cached inputs and answer history, kept in a subdirectory per year (also set by AOC_CACHE_DIR)
+    #[arg(long, global = true)]
+    pub cache_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Run solution for a day (both parts if part is omitted) or print a timing table for all implemented days
+    Run {
+        #[arg(required_unless_present = "all")]
+        day: Option<u32>,
+        part: Option<u32>,
+        #[arg(long, conflicts_with_all = ["day", "part"])]
+        all: bool,
+        /// Read input from this file ("-" for stdin) instead of the cached one
+        #[arg(long, value_name = "PATH", conflicts_with = "all")]
+        input: Option<PathBuf>,
+        /// Number of parts to run in parallel with --all, 1 by default (timings are less accurate when above 1)
+        #[arg(long, short, conflicts_with = "day")]
+        jobs: Option<usize>,
+        /// Output format: text or json (one object per part and line)
+        #[arg(long, default_value = "text")]
+        format: OutputFormat,
+        /// How long-running parts report progress: bar, none or json (lines on stderr), json by default with --format json
+        #[arg(long)]
+        progress: Option<ProgressMode>,
+    },
+    /// Submit an answer (computed by the solution if omitted) to advent of code website
+    Submit {
+        day: u32,
+        part: u32,
+        answer: Option<String>,
+        /// Submit even if answer history says the answer is wrong
+        #[arg(long)]
+        force: bool,
+    },
+    /// Inspect or update history of submitted answers
+    Answers {
+        #[command(subcommand)]
+        command: AnswersCommand,
+    },
+    /// Check that solutions still produce accepted answers, or answers not ruled out by rejected ones, on cached inputs
+    Verify,
+    /// Manage cached puzzle inputs
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+    /// Download puzzle description, save its examples as test fixtures and list the expected answers it mentions
+    Examples {
+        day: u32,
+        /// Overwrite existing fixtures
+        #[arg(long)]
+        force: bool,
+    },
+    /// Show stars and scores of a private leaderboard (downloaded at most once per 15 minutes)
+    Leaderboard {
+        /// Leaderboard id, the number at the end of its url
+        id: u64,
+        /// Show completion times for this day instead of the overview
+        #[arg(long)]
+        day: Option<u32>,
+    },
+    /// Manage advent of code session cookie
+    Session {
+        #[command(subcommand)]
+        command: SessionCommand,
+    },
+    /// Create module skeleton for a new day and register it with the runner
+    New {
+        day: u32,
+    },
+    /// Benchmark a single part on cached input
+    Bench {
+        day: u32,
+        part: u32,
+        #[arg(long, default_value_t = 10)]
+        iterations: u32,
+        #[arg(long, default_value_t = 3)]
+        warmup: u32,
+        /// Compare medians against the ones saved in this file
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Save medians to this file
+        #[arg(long)]
+        save_baseline: Option<PathBuf>,
+        /// Allowed slowdown compared to baseline, in percent
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AnswersCommand {
+    /// Show recorded answers and bounds derived from them
+    Show {
+        day: Option<u32>,
+    },
+    /// Record verdict for an answer submitted outside of this tool
+    Record {
+        day: u32,
+        part: u32,
+        answer: String,
+        /// One of: correct, too_high, too_low, wrong
+        verdict: Verdict,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum CacheCommand {
+    /// Find cached inputs that are error pages instead of puzzle input
+    Audit {
+        /// Remove invalid cached inputs
+        #[arg(long)]
+        evict: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum SessionCommand {
+    /// Save session cookie to the config directory (read from stdin if omitted)
+    Set {
+        cookie: Option<String>,
+    },
+    /// Check that session cookie is set and still accepted by advent of code website
+    Check,
+}
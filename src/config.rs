@@ -0,0 +1,282 @@
+use {
+    std::{env::var, fs::read_to_string, io, path::{Path, PathBuf}, sync::OnceLock},
+    serde::Deserialize,
+};
+
+const DEFAULT_CONFIG_PATH: &str = "aoc.toml";
+// repository from Cargo.toml, always sent in User-Agent header so that advent of code maintainers can reach out
+pub const REPOSITORY: &str = env!("CARGO_PKG_REPOSITORY");
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub year: u32,
+    pub base_url: String,
+    pub cache_dir: PathBuf,
+    // never access advent of code website
+    pub offline: bool,
+    // email, included in User-Agent header after the repository
+    pub contact: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: 2024,
+            base_url: "https://adventofcode.com".to_owned(),
+            cache_dir: PathBuf::from("data"),
+            offline: false,
+            contact: None,
+        }
+    }
+}
+
+// values passed as command line arguments, these take priority over env and config file.
+#[derive(Default)]
+pub struct ConfigOverrides {
+    pub config_path: Option<PathBuf>,
+    pub year: Option<u32>,
+    pub base_url: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+    pub offline: bool,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    year: Option<u32>,
+    base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+    offline: Option<bool>,
+    contact: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read {
+        path: PathBuf,
+        err: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        err: toml::de::Error,
+    },
+    InvalidEnv {
+        name: &'static str,
+        value: String,
+    },
+    MissingContact,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read { path, err } => write!(f, "failed to read config file {}: {}", path.display(), err),
+            Self::Parse { path, err } => write!(f, "failed to parse config file {}: {}", path.display(), err),
+            Self::InvalidEnv { name, value } => write!(f, "invalid value for {}: \"{}\"", name, value),
+            Self::MissingContact => write!(
+                f,
+                "no contact for User-Agent header, set repository in Cargo.toml or contact in config file (or AOC_CONTACT)"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    // defaults, then config file (aoc.toml or AOC_CONFIG), then env variables, then command line arguments.
+    pub fn load(overrides: &ConfigOverrides) -> Result<Self, ConfigError> {
+        let env = |name: &str| var(name).ok().filter(|v| !v.is_empty());
+
+        let (config_path, required) = match (&overrides.config_path, env("AOC_CONFIG")) {
+            (Some(path), _) => (path.clone(), true),
+            (None, Some(path)) => (PathBuf::from(path), true),
+            (None, None) => (PathBuf::from(DEFAULT_CONFIG_PATH), false),
+        };
+
+        let mut config = Self::default();
+        if required || config_path.exists() {
+            config.apply_file(&read_config_file(&config_path)?);
+        }
+        config.apply_env(env)?;
+        config.apply_overrides(overrides);
+
+        Ok(config)
+    }
+
+    // advent of code must be able to identify who sends requests, checked before the first request is made
+    pub fn check_contact(&self, repository: &str) -> Result<(), ConfigError> {
+        if repository.is_empty() && self.contact.is_none() {
+            return Err(ConfigError::MissingContact);
+        }
+
+        Ok(())
+    }
+
+    fn apply_file(&mut self, file: &ConfigFile) {
+        if let Some(year) = file.year {
+            self.year = year;
+        }
+        if let Some(base_url) = &file.base_url {
+            self.base_url = base_url.clone();
+        }
+        if let Some(cache_dir) = &file.cache_dir {
+            self.cache_dir = cache_dir.clone();
+        }
+        if let Some(offline) = file.offline {
+            self.offline = offline;
+        }
+        if let Some(contact) = &file.contact {
+            self.contact = Some(contact.clone());
+        }
+    }
+
+    fn apply_env(&mut self, env: impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        if let Some(year) = env("AOC_YEAR") {
+            self.year = year.parse().map_err(|_| ConfigError::InvalidEnv { name: "AOC_YEAR", value: year })?;
+        }
+        if let Some(base_url) = env("AOC_BASE_URL") {
+            self.base_url = base_url;
+        }
+        if let Some(cache_dir) = env("AOC_CACHE_DIR") {
+            self.cache_dir = PathBuf::from(cache_dir);
+        }
+        if let Some(offline) = env("AOC_OFFLINE") {
+            self.offline = offline != "0";
+        }
+        if let Some(contact) = env("AOC_CONTACT") {
+            self.contact = Some(contact);
+        }
+
+        Ok(())
+    }
+
+    fn apply_overrides(&mut self, overrides: &ConfigOverrides) {
+        if let Some(year) = overrides.year {
+            self.year = year;
+        }
+        if let Some(base_url) = &overrides.base_url {
+            self.base_url = base_url.clone();
+        }
+        if let Some(cache_dir) = &overrides.cache_dir {
+            self.cache_dir = cache_dir.clone();
+        }
+        if overrides.offline {
+            self.offline = true;
+        }
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.trim_end_matches('/')
+    }
+
+    pub fn day_url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}", self.base_url(), self.year, day)
+    }
+
+    // inputs and answers differ between years, so they are cached separately
+    pub fn year_cache_dir(&self) -> PathBuf {
+        self.cache_dir.join(self.year.to_string())
+    }
+}
+
+fn read_config_file(path: &Path) -> Result<ConfigFile, ConfigError> {
+    let content = read_to_string(path).map_err(|err| ConfigError::Read { path: path.to_owned(), err })?;
+    toml::from_str(&content).map_err(|err| ConfigError::Parse { path: path.to_owned(), err })
+}
+
+pub fn init(config: Config) {
+    CONFIG.set(config).expect("config is already initialized");
+}
+
+// tests always get default config in offline mode, so that they never access the network.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(|| if cfg!(test) {
+        Config {
+            offline: true,
+            ..Config::default()
+        }
+    } else {
+        Config::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        std::collections::HashMap,
+        super::*,
+    };
+
+    #[test]
+    fn test_apply_file() {
+        let mut config = Config::default();
+        config.apply_file(&toml::from_str("year = 2023\nbase_url = \"http://localhost:8080/\"").unwrap());
+
+        assert_eq!(config.year, 2023);
+        assert_eq!(config.base_url(), "http://localhost:8080");
+        assert_eq!(config.cache_dir, PathBuf::from("data"));
+        assert_eq!(config.day_url(5), "http://localhost:8080/2023/day/5");
+    }
+
+    #[test]
+    fn test_unknown_config_file_field() {
+        assert!(toml::from_str::<ConfigFile>("yaer = 2023").is_err());
+    }
+
+    #[test]
+    fn test_apply_env() {
+        let env = HashMap::from([
+            ("AOC_YEAR", "2022"),
+            ("AOC_CACHE_DIR", "/tmp/aoc"),
+            ("AOC_OFFLINE", "1"),
+            ("AOC_CONTACT", "someone@example.com"),
+        ]);
+
+        let mut config = Config::default();
+        config.apply_env(|name| env.get(name).map(|v| v.to_string())).unwrap();
+
+        assert_eq!(config, Config {
+            year: 2022,
+            base_url: "https://adventofcode.com".to_owned(),
+            cache_dir: PathBuf::from("/tmp/aoc"),
+            offline: true,
+            contact: Some("someone@example.com".to_owned()),
+        });
+    }
+
+    #[test]
+    fn test_invalid_env() {
+        let mut config = Config::default();
+        assert!(config.apply_env(|name| (name == "AOC_YEAR").then(|| "next".to_owned())).is_err());
+    }
+
+    #[test]
+    fn test_check_contact() {
+        let config = Config::default();
+        assert!(matches!(config.check_contact(""), Err(ConfigError::MissingContact)));
+        assert!(config.check_contact("https://example.com/aoc").is_ok());
+
+        let with_contact = Config {
+            contact: Some("test@example.com".to_owned()),
+            ..Config::default()
+        };
+        assert!(with_contact.check_contact("").is_ok());
+    }
+
+    #[test]
+    fn test_overrides_take_priority() {
+        let mut config = Config::default();
+        config.apply_file(&toml::from_str("year = 2023\ncache_dir = \"inputs\"").unwrap());
+        config.apply_overrides(&ConfigOverrides {
+            year: Some(2015),
+            ..ConfigOverrides::default()
+        });
+
+        assert_eq!(config.year, 2015);
+        assert_eq!(config.cache_dir, PathBuf::from("inputs"));
+        assert_eq!(config.year_cache_dir(), PathBuf::from("inputs").join("2015"));
+    }
+}
@@ -1,14 +1,32 @@
-use crate::utils::download_input;
+#![allow(clippy::cast_abs_to_unsigned, clippy::useless_conversion)]
+
+use crate::{parse::{self, ParseError}, solution::Solution};
 
 pub mod part1;
 pub mod part2;
 
-pub fn parse_input() -> Vec<(u64, u64)> {
-    download_input(1)
-        .lines()
-        .map(|v| {
-            let spl = v.split(" ").filter(|v| !v.is_empty()).collect::<Vec<_>>();
-            (spl[0].parse::<u64>().unwrap(), spl[1].parse::<u64>().unwrap())
-        })
-        .collect::<Vec<_>>()
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<(u64, u64)>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input).unwrap_or_else(|err| panic!("invalid input: {}", err))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<(u64, u64)>, ParseError> {
+    parse::lines(input)
+        .map(|line| line.integer_array().map(|[first, second]| (first, second)))
+        .collect()
 }
@@ -1,11 +1,4 @@
-use super::parse_input;
-
-#[allow(dead_code)]
-pub fn run() {
-    println!("result is: {}", solve(parse_input()));
-}
-
-fn solve(input: Vec<(u64, u64)>) -> u64 {
+pub fn solve(input: &[(u64, u64)]) -> u64 {
     let mut first = input.iter().map(|v| v.0).collect::<Vec<_>>();
     let mut second = input.iter().map(|v| v.1).collect::<Vec<_>>();
 
@@ -21,7 +14,7 @@ mod tests {
 
     #[test]
     fn test_example() {
-        assert_eq!(solve(vec![
+        assert_eq!(solve(&[
             (3, 4),
             (4, 3),
             (2, 5),
@@ -1,14 +1,6 @@
-use {
-    rpds::HashTrieMap,
-    super::parse_input,
-};
+use rpds::HashTrieMap;
 
-#[allow(dead_code)]
-pub fn run() {
-    println!("result is: {}", solve(parse_input()));
-}
-
-fn solve(input: Vec<(u64, u64)>) -> u64 {
+pub fn solve(input: &[(u64, u64)]) -> u64 {
     let first = input.iter().map(|v| v.0).collect::<Vec<_>>();
     let second = input.iter().map(|v| v.1).collect::<Vec<_>>();
 
@@ -25,7 +17,7 @@ mod tests {
 
     #[test]
     fn test_example() {
-        assert_eq!(solve(vec![
+        assert_eq!(solve(&[
             (3, 4),
             (4, 3),
             (2, 5),
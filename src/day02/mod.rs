@@ -1,8 +1,30 @@
-use crate::utils::download_input;
+#![allow(clippy::bool_assert_comparison)]
+
+use crate::{parse::{self, ParseError}, solution::Solution};
 
 pub mod part1;
 pub mod part2;
 
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Report>;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input).unwrap_or_else(|err| panic!("invalid input: {}", err))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input)
+    }
+}
+
 #[derive(Debug)]
 pub struct Report {
     levels: Vec<u32>,
@@ -27,14 +49,12 @@ impl Direction {
     }
 }
 
-pub fn parse_input() -> Vec<Report> {
-    download_input(2)
-        .lines()
-        .filter(|v| !v.is_empty())
-        .map(|line| Report {
-            levels: line.split(" ").map(|v| v.parse().unwrap()).collect(),
-        })
-        .collect::<Vec<_>>()
+pub fn parse_input(input: &str) -> Result<Vec<Report>, ParseError> {
+    parse::lines(input)
+        .map(|line| Ok(Report {
+            levels: line.separated(' ')?,
+        }))
+        .collect()
 }
 
 fn is_report_safe(report: &Report) -> bool {
@@ -197,4 +217,12 @@ mod tests {
             },
         ), true);
     }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            parse_input("7 6 4\n1 2,7 8\n").unwrap_err().to_string(),
+            "line 2, column 3: invalid value \"2,7\": invalid digit found in string"
+        );
+    }
 }
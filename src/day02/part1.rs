@@ -1,13 +1,7 @@
-use super::{Report, parse_input, is_report_safe};
+use super::{Report, is_report_safe};
 
-#[allow(dead_code)]
-pub fn run() {
-    let result = solve(parse_input());
-    println!("result: {}", result);
-}
-
-fn solve(reports: Vec<Report>) -> u32 {
-    reports.into_iter().filter(|v| is_report_safe(v)).count() as u32
+pub fn solve(reports: &[Report]) -> u32 {
+    reports.iter().filter(|v| is_report_safe(v)).count() as u32
 }
 
 #[cfg(test)]
@@ -17,7 +11,7 @@ mod tests {
     #[test]
     fn test_solve_example() {
         assert_eq!(
-            solve(vec![
+            solve(&[
                 Report {
                     levels: vec![7, 6, 4, 2, 1],
                 },
@@ -1,6 +1,30 @@
+#![allow(clippy::get_first, clippy::match_like_matches_macro)]
+
+use crate::solution::Solution;
+
 pub mod part1;
 pub mod part2;
 
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        input.to_owned()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input)
+    }
+}
+
 #[derive(Debug, Clone)]
 enum Operation {
     Do,
@@ -1,10 +1,3 @@
-use {
-    crate::utils::download_input,
-    super::solve,
-};
-
-#[allow(dead_code)]
-pub fn run() {
-    let result = solve(download_input(3), false, true);
-    println!("result: {}", result);
+pub fn solve(input: &str) -> u32 {
+    super::solve(input, false, true)
 }
@@ -1,74 +1,34 @@
+#![allow(clippy::collapsible_match)]
+
+use crate::{grid::Grid, point::Point, solution::Solution};
+
 pub mod part1;
 pub mod part2;
 
-type Direction3 = [[isize; 2]; 3];
-type Direction4 = [[isize; 2]; 4];
+pub struct Day04;
 
-struct Grid {
-    // (0, 0) is top left corner
-    grid: Vec<String>,
-}
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    type Output1 = u32;
+    type Output2 = u32;
 
-impl Grid {
-    pub fn new(grid: String) -> Self {
-        Self {
-            grid: grid.lines().filter(|v| !v.is_empty()).map(|v| v.to_owned()).collect(),
-        }
+    fn parse(input: &str) -> Self::Input {
+        Grid::parse(input, |c| c)
     }
 
-    fn rows(&self) -> usize {
-        self.grid.len()
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
     }
 
-    fn columns(&self) -> usize {
-        self.grid[0].len()
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input)
     }
+}
 
-    fn at(&self, row: usize, column: usize) -> Option<char> {
-        self.grid.get(row)?.chars().nth(column)
-    }
-
-    fn select_word3(&self, row: usize, column: usize, direction: &Direction3) -> Option<String> {
-        let positions = direction
-            .iter()
-            .map(|[d_row, d_column]| (row as isize + d_row, column as isize + d_column))
-            .map(|(row, column)| {
-                if row < 0 {
-                    None
-                } else if column < 0 {
-                    None
-                } else {
-                    self.at(row as usize, column as usize)
-                }
-            })
-            .collect::<Vec<_>>();
-
-        if positions.iter().find(|v| v.is_none()).is_some() {
-            return None;
-        }
-
-        Some(positions.into_iter().map(|v| v.unwrap()).collect())
-    }
-
-    fn select_word4(&self, row: usize, column: usize, direction: &Direction4) -> Option<String> {
-        let positions = direction
-            .iter()
-            .map(|[d_row, d_column]| (row as isize + d_row, column as isize + d_column))
-            .map(|(row, column)| {
-                if row < 0 {
-                    None
-                } else if column < 0 {
-                    None
-                } else {
-                    self.at(row as usize, column as usize)
-                }
-            })
-            .collect::<Vec<_>>();
-
-        if positions.iter().find(|v| v.is_none()).is_some() {
-            return None;
-        }
-
-        Some(positions.into_iter().map(|v| v.unwrap()).collect())
-    }
+// selects chars at given offsets from a point, None if any of them is outside of grid
+fn select_word(grid: &Grid<char>, point: Point, offsets: &[Point]) -> Option<String> {
+    offsets
+        .iter()
+        .map(|offset| grid.get(point + *offset))
+        .collect()
 }
@@ -1,44 +1,33 @@
 use {
-    crate::utils::download_input,
-    super::{Grid, Direction3},
+    crate::{grid::Grid, point::Point},
+    super::select_word,
 };
 
 // think of it like clock hand
-const DIAGONAL_1: Direction3 = [
-    [1, -1],
-    [0, 0],
-    [-1, 1],
+const DIAGONAL_1: [Point; 3] = [
+    Point::new(-1, 1),
+    Point::new(0, 0),
+    Point::new(1, -1),
 ];
-const DIAGONAL_2: Direction3 = [
-    [-1, -1],
-    [0, 0],
-    [1, 1],
+const DIAGONAL_2: [Point; 3] = [
+    Point::new(-1, -1),
+    Point::new(0, 0),
+    Point::new(1, 1),
 ];
 
 const WORD: &str = "MAS";
 const WORD_REVERSED: &str = "SAM";
 
-#[allow(dead_code)]
-pub fn run() {
-    println!("result: {}", solve(Grid::new(download_input(4))));
+pub fn solve(grid: &Grid<char>) -> u32 {
+    grid.points().map(|point| solve_at_position(grid, point)).sum()
 }
 
-fn solve(grid: Grid) -> u32 {
-    let mut total = 0;
-    for row in 0..grid.rows() {
-        for column in 0..grid.columns() {
-            total += solve_at_position(&grid, row, column);
-        }
-    }
-    total
-}
-
-fn solve_at_position(grid: &Grid, row: usize, column: usize) -> u32 {
-    if !grid.at(row, column).map(|v| v == 'A').unwrap_or(false) {
+fn solve_at_position(grid: &Grid<char>, point: Point) -> u32 {
+    if grid.get(point) != Some(&'A') {
         return 0;
     }
 
-    match grid.select_word3(row, column, &DIAGONAL_1) {
+    match select_word(grid, point, &DIAGONAL_1) {
         None => return 0,
         Some(word) => if word == WORD || word == WORD_REVERSED {
             // ok
@@ -46,7 +35,7 @@ fn solve_at_position(grid: &Grid, row: usize, column: usize) -> u32 {
             return 0
         }
     };
-    match grid.select_word3(row, column, &DIAGONAL_2) {
+    match select_word(grid, point, &DIAGONAL_2) {
         None => return 0,
         Some(word) => if word == WORD || word == WORD_REVERSED {
             // ok
@@ -60,24 +49,13 @@ fn solve_at_position(grid: &Grid, row: usize, column: usize) -> u32 {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        crate::examples::example_input,
+        super::*,
+    };
 
     #[test]
     fn test_example() {
-        assert_eq!(
-            solve(
-                Grid::new(r#"MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX"#.to_owned())
-            ),
-            9
-        );
+        assert_eq!(solve(&Grid::parse(&example_input(4, 2), |c| c)), 9);
     }
 }
@@ -1,83 +1,38 @@
+#![allow(clippy::partialeq_ne_impl)]
+
 use {
     std::collections::HashMap,
-    lazy_static::lazy_static,
-    crate::utils::download_input,
+    crate::{parse::{self, ParseError}, solution::Solution},
 };
 
 #[cfg(test)]
-pub const EXAMPLE_RULES: [Rule; 21] = [
-    Rule::new(PageNumber::new(47), PageNumber::new(53)),
-    Rule::new(PageNumber::new(97), PageNumber::new(13)),
-    Rule::new(PageNumber::new(97), PageNumber::new(61)),
-    Rule::new(PageNumber::new(97), PageNumber::new(47)),
-    Rule::new(PageNumber::new(75), PageNumber::new(29)),
-    Rule::new(PageNumber::new(61), PageNumber::new(13)),
-    Rule::new(PageNumber::new(75), PageNumber::new(53)),
-    Rule::new(PageNumber::new(29), PageNumber::new(13)),
-    Rule::new(PageNumber::new(97), PageNumber::new(29)),
-    Rule::new(PageNumber::new(53), PageNumber::new(29)),
-    Rule::new(PageNumber::new(61), PageNumber::new(53)),
-    Rule::new(PageNumber::new(97), PageNumber::new(53)),
-    Rule::new(PageNumber::new(61), PageNumber::new(29)),
-    Rule::new(PageNumber::new(47), PageNumber::new(13)),
-    Rule::new(PageNumber::new(75), PageNumber::new(47)),
-    Rule::new(PageNumber::new(97), PageNumber::new(75)),
-    Rule::new(PageNumber::new(47), PageNumber::new(61)),
-    Rule::new(PageNumber::new(75), PageNumber::new(61)),
-    Rule::new(PageNumber::new(47), PageNumber::new(29)),
-    Rule::new(PageNumber::new(75), PageNumber::new(13)),
-    Rule::new(PageNumber::new(53), PageNumber::new(13)),
-];
-
-lazy_static! {
-    pub static ref EXAMPLE_UPDATE_1: Update = Update::new(vec![
-        PageNumber::new(75),
-        PageNumber::new(47),
-        PageNumber::new(61),
-        PageNumber::new(53),
-        PageNumber::new(29),
-    ]);
-
-    pub static ref EXAMPLE_UPDATE_2: Update = Update::new(vec![
-        PageNumber::new(97),
-        PageNumber::new(61),
-        PageNumber::new(53),
-        PageNumber::new(29),
-        PageNumber::new(13),
-    ]);
-
-    pub static ref EXAMPLE_UPDATE_3: Update = Update::new(vec![
-        PageNumber::new(75),
-        PageNumber::new(29),
-        PageNumber::new(13),
-    ]);
-
-    pub static ref EXAMPLE_UPDATE_4: Update = Update::new(vec![
-        PageNumber::new(75),
-        PageNumber::new(97),
-        PageNumber::new(47),
-        PageNumber::new(61),
-        PageNumber::new(53),
-    ]);
-
-    pub static ref EXAMPLE_UPDATE_5: Update = Update::new(vec![
-        PageNumber::new(61),
-        PageNumber::new(13),
-        PageNumber::new(29),
-    ]);
-
-    pub static ref EXAMPLE_UPDATE_6: Update = Update::new(vec![
-        PageNumber::new(97),
-        PageNumber::new(13),
-        PageNumber::new(75),
-        PageNumber::new(29),
-        PageNumber::new(47),
-    ]);
+lazy_static::lazy_static! {
+    pub static ref EXAMPLE: (Vec<Rule>, Vec<Update>) = parse_input(&crate::examples::example_input(5, 1)).unwrap();
 }
 
 pub mod part1;
 pub mod part2;
 
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Rule>, Vec<Update>);
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input).unwrap_or_else(|err| panic!("invalid input: {}", err))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Self::Output1 {
+        part1::solve(rules, updates)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Self::Output2 {
+        part2::solve(rules, updates)
+    }
+}
+
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 pub struct PageNumber(u32);
 
@@ -109,6 +64,7 @@ pub struct Update {
 }
 
 impl Update {
+    #[allow(dead_code)]
     pub fn empty() -> Self {
         Self {
             index: HashMap::new(),
@@ -129,6 +85,7 @@ impl Update {
         }
     }
 
+    #[allow(dead_code)]
     pub fn append(&self, page: PageNumber) -> Self {
         let mut index = self.index.clone();
         index.insert(page.clone(), index.len());
@@ -179,28 +136,29 @@ impl PartialEq for Update {
     }
 }
 
-pub fn parse_input() -> (Vec<Rule>, Vec<Update>) {
-    let input = download_input(5);
-    let input = input.split("\n\n").collect::<Vec<_>>();
-
-    let rules = input.get(0).unwrap()
-        .lines()
-        .filter(|line| !line.is_empty())
+pub fn parse_input(input: &str) -> Result<(Vec<Rule>, Vec<Update>), ParseError> {
+    let sections = parse::sections(input);
+    let [rules, updates] = sections.as_slice() else {
+        return Err(ParseError::new(
+            input.lines().count() + 1,
+            1,
+            format!("expected page ordering rules and updates separated by a blank line, found {} sections", sections.len())
+        ));
+    };
+
+    let rules = rules
+        .iter()
         .map(|line| {
-            let spl = line.split("|").collect::<Vec<_>>();
-            Rule::new(
-                PageNumber::new(spl.get(0).unwrap().parse().unwrap()),
-                PageNumber::new(spl.get(1).unwrap().parse().unwrap()),
-            )
+            let [page1, page2] = line.separated_array('|')?;
+            Ok(Rule::new(PageNumber::new(page1), PageNumber::new(page2)))
         })
-        .collect::<Vec<_>>();
-    let updates = input.get(1).unwrap()
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| Update::new(line.split(",").map(|v| PageNumber::new(v.parse().unwrap())).collect()))
-        .collect();
-
-    (rules, updates)
+        .collect::<Result<Vec<_>, _>>()?;
+    let updates = updates
+        .iter()
+        .map(|line| Ok(Update::new(line.separated(',')?.into_iter().map(PageNumber::new).collect())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((rules, updates))
 }
 
 #[cfg(test)]
@@ -209,34 +167,50 @@ mod tests {
 
     #[test]
     fn test_example_update_1() {
-        assert!(EXAMPLE_UPDATE_1.satisfies_rules(&EXAMPLE_RULES));
-        assert_eq!(EXAMPLE_UPDATE_1.middle_page_number(), &PageNumber::new(61));
+        assert!(EXAMPLE.1[0].satisfies_rules(&EXAMPLE.0));
+        assert_eq!(EXAMPLE.1[0].middle_page_number(), &PageNumber::new(61));
     }
 
     #[test]
     fn test_example_update_2() {
-        assert!(EXAMPLE_UPDATE_2.satisfies_rules(&EXAMPLE_RULES));
-        assert_eq!(EXAMPLE_UPDATE_2.middle_page_number(), &PageNumber::new(53));
+        assert!(EXAMPLE.1[1].satisfies_rules(&EXAMPLE.0));
+        assert_eq!(EXAMPLE.1[1].middle_page_number(), &PageNumber::new(53));
     }
 
     #[test]
     fn test_example_update_3() {
-        assert!(EXAMPLE_UPDATE_3.satisfies_rules(&EXAMPLE_RULES));
-        assert_eq!(EXAMPLE_UPDATE_3.middle_page_number(), &PageNumber::new(29));
+        assert!(EXAMPLE.1[2].satisfies_rules(&EXAMPLE.0));
+        assert_eq!(EXAMPLE.1[2].middle_page_number(), &PageNumber::new(29));
     }
 
     #[test]
     fn test_example_update_4() {
-        assert!(!EXAMPLE_UPDATE_4.satisfies_rules(&EXAMPLE_RULES));
+        assert!(!EXAMPLE.1[3].satisfies_rules(&EXAMPLE.0));
     }
 
     #[test]
     fn test_example_update_5() {
-        assert!(!EXAMPLE_UPDATE_5.satisfies_rules(&EXAMPLE_RULES));
+        assert!(!EXAMPLE.1[4].satisfies_rules(&EXAMPLE.0));
     }
 
     #[test]
     fn test_example_update_6() {
-        assert!(!&EXAMPLE_UPDATE_6.satisfies_rules(&EXAMPLE_RULES));
+        assert!(!EXAMPLE.1[5].satisfies_rules(&EXAMPLE.0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_input("47|53\n97|13\n").err(),
+            Some(ParseError::new(3, 1, "expected page ordering rules and updates separated by a blank line, found 1 sections"))
+        );
+        assert_eq!(
+            parse_input("47|53\n97|13|75\n\n75,47\n").err().map(|err| err.to_string()),
+            Some("line 2, column 1: expected 2 values, found 3".to_owned())
+        );
+        assert_eq!(
+            parse_input("47|53\n\n75,47;61\n").err().map(|err| err.to_string()),
+            Some("line 3, column 4: invalid value \"47;61\": invalid digit found in string".to_owned())
+        );
     }
 }
@@ -1,19 +1,12 @@
 use super::{
     Rule,
     Update,
-    parse_input,
 };
 
-#[allow(dead_code)]
-pub fn run() {
-    let (rules, updates) = parse_input();
-    println!("result: {}", solve(rules, updates));
-}
-
-fn solve(rules: Vec<Rule>, updates: Vec<Update>) -> u32 {
+pub fn solve(rules: &[Rule], updates: &[Update]) -> u32 {
     updates
-        .into_iter()
-        .filter(|update| update.satisfies_rules(&rules))
+        .iter()
+        .filter(|update| update.satisfies_rules(rules))
         .map(|update| update.middle_page_number().0)
         .sum()
 }
@@ -22,31 +15,13 @@ fn solve(rules: Vec<Rule>, updates: Vec<Update>) -> u32 {
 mod tests {
     use super::{
         *,
-        super::{
-            EXAMPLE_RULES,
-            EXAMPLE_UPDATE_1,
-            EXAMPLE_UPDATE_2,
-            EXAMPLE_UPDATE_3,
-            EXAMPLE_UPDATE_4,
-            EXAMPLE_UPDATE_5,
-            EXAMPLE_UPDATE_6,
-        },
+        super::EXAMPLE,
     };
 
     #[test]
     fn test_solve_example() {
         assert_eq!(
-            solve(
-                EXAMPLE_RULES.to_vec(),
-                vec![
-                    EXAMPLE_UPDATE_1.clone(),
-                    EXAMPLE_UPDATE_2.clone(),
-                    EXAMPLE_UPDATE_3.clone(),
-                    EXAMPLE_UPDATE_4.clone(),
-                    EXAMPLE_UPDATE_5.clone(),
-                    EXAMPLE_UPDATE_6.clone(),
-                ],
-            ),
+            solve(&EXAMPLE.0, &EXAMPLE.1),
             143
         );
     }
@@ -1,19 +1,13 @@
 use {
     std::cmp::Ordering,
-    super::{PageNumber, Update, Rule, parse_input},
+    super::{PageNumber, Update, Rule},
 };
 
-#[allow(dead_code)]
-pub fn run() {
-    let (rules, updates) = parse_input();
-    println!("result: {}", solve(rules, updates));
-}
-
-fn solve(rules: Vec<Rule>, updates: Vec<Update>) -> u32 {
+pub fn solve(rules: &[Rule], updates: &[Update]) -> u32 {
     updates
-        .into_iter()
-        .filter(|update| !update.satisfies_rules(&rules))
-        .map(|update| create_correctly_ordered(&rules, &update.pages))
+        .iter()
+        .filter(|update| !update.satisfies_rules(rules))
+        .map(|update| create_correctly_ordered(rules, &update.pages))
         .map(|update| update.middle_page_number().0)
         .sum()
 }
@@ -40,21 +34,13 @@ pub fn create_correctly_ordered(rules: &[Rule], pages: &[PageNumber]) -> Update
 mod tests {
     use super::{
         *,
-        super::{
-            EXAMPLE_RULES,
-            EXAMPLE_UPDATE_1,
-            EXAMPLE_UPDATE_2,
-            EXAMPLE_UPDATE_3,
-            EXAMPLE_UPDATE_4,
-            EXAMPLE_UPDATE_5,
-            EXAMPLE_UPDATE_6,
-        },
+        super::EXAMPLE,
     };
 
     #[test]
     fn test_example_update_4_fixed() {
         assert_eq!(
-            create_correctly_ordered(&EXAMPLE_RULES, &EXAMPLE_UPDATE_4.pages),
+            create_correctly_ordered(&EXAMPLE.0, &EXAMPLE.1[3].pages),
             Update::new(vec![
                 PageNumber::new(97),
                 PageNumber::new(75),
@@ -68,7 +54,7 @@ mod tests {
     #[test]
     fn test_example_update_5_fixed() {
         assert_eq!(
-            create_correctly_ordered(&EXAMPLE_RULES, &EXAMPLE_UPDATE_5.pages),
+            create_correctly_ordered(&EXAMPLE.0, &EXAMPLE.1[4].pages),
             Update::new(vec![
                 PageNumber::new(61),
                 PageNumber::new(29),
@@ -80,7 +66,7 @@ mod tests {
     #[test]
     fn test_example_update_6_fixed() {
         assert_eq!(
-            create_correctly_ordered(&EXAMPLE_RULES, &EXAMPLE_UPDATE_6.pages),
+            create_correctly_ordered(&EXAMPLE.0, &EXAMPLE.1[5].pages),
             Update::new(vec![
                 PageNumber::new(97),
                 PageNumber::new(75),
@@ -94,17 +80,7 @@ mod tests {
     #[test]
     fn test_solve_example() {
         assert_eq!(
-            solve(
-                EXAMPLE_RULES.to_vec(),
-                vec![
-                    EXAMPLE_UPDATE_1.clone(),
-                    EXAMPLE_UPDATE_2.clone(),
-                    EXAMPLE_UPDATE_3.clone(),
-                    EXAMPLE_UPDATE_4.clone(),
-                    EXAMPLE_UPDATE_5.clone(),
-                    EXAMPLE_UPDATE_6.clone(),
-                ],
-            ),
+            solve(&EXAMPLE.0, &EXAMPLE.1),
             123
         );
     }
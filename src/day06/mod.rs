@@ -1,83 +1,58 @@
-use std::collections::HashSet;
+#![allow(clippy::bool_assert_comparison)]
+
+use {
+    std::collections::HashSet,
+    crate::{
+        grid::Grid,
+        parse::{self, ParseError},
+        point::{Direction, Point},
+        progress::{NoProgress, Progress},
+        solution::Solution,
+    },
+};
 
 pub mod part1;
 pub mod part2;
 
-#[derive(Clone)]
-enum Object {
-    Empty,
-    Obstacle,
-}
+pub struct Day06;
 
-#[derive(Eq, PartialEq, Hash, Clone)]
-pub struct Position {
-    x: u32,
-    y: u32,
-}
+impl Solution for Day06 {
+    type Input = World;
+    type Output1 = u32;
+    type Output2 = u32;
 
-impl Position {
-    fn new(x: u32, y: u32) -> Self {
-        Self {
-            x,
-            y,
-        }
+    fn parse(input: &str) -> Self::Input {
+        parse_world(input).unwrap_or_else(|err| panic!("invalid input: {}", err))
     }
 
-    fn move_in_direction(&self, direction: &Direction) -> Option<Self> {
-        Some(match direction {
-            Direction::Top => if self.y != 0 {
-                Self::new(self.x, self.y - 1)
-            } else {
-                return None;
-            },
-            Direction::Right => Self::new(self.x + 1, self.y),
-            Direction::Bottom => Self::new(self.x, self.y + 1),
-            Direction::Left => if self.x != 0 {
-                Self::new(self.x - 1, self.y)
-            } else {
-                return None;
-            },
-        })
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
     }
-}
-
-#[derive(Eq, PartialEq, Hash, Clone)]
-enum Direction {
-    Top,
-    Right,
-    Bottom,
-    Left,
-}
 
-impl Direction {
-    pub fn turn_right(&self) -> Self {
-        match self {
-            Self::Top => Self::Right,
-            Self::Right => Self::Bottom,
-            Self::Bottom => Self::Left,
-            Self::Left => Self::Top,
-        }
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input, &NoProgress)
     }
 
-    #[allow(dead_code)]
-    pub fn is_vertical(&self) -> bool {
-        match self {
-            Self::Top | Self::Bottom => true,
-            Self::Left | Self::Right => false,
-        }
+    fn part2_with_progress(input: &Self::Input, progress: &dyn Progress) -> Self::Output2 {
+        part2::solve(input, progress)
     }
 }
 
+#[derive(Clone)]
+enum Object {
+    Empty,
+    Obstacle,
+}
+
 #[derive(Clone)]
 pub struct World {
-    // top left is (0, 0)
-    map: Vec<Vec<Object>>,
-    guard_position: Option<Position>,
+    map: Grid<Object>,
+    guard_position: Option<Point>,
     guard_direction: Option<Direction>,
 }
 
 impl World {
-    fn new(map: Vec<Vec<Object>>, guard_position: Position, guard_direction: Direction) -> Self {
+    fn new(map: Grid<Object>, guard_position: Point, guard_direction: Direction) -> Self {
         Self {
             map,
             guard_position: Some(guard_position),
@@ -85,18 +60,14 @@ impl World {
         }
     }
 
-    fn object_at(&self, position: &Position) -> Option<&Object> {
-        self.map.get(position.y as usize).and_then(|row| row.get(position.x as usize))
-    }
-
-    pub fn with_obstacle_at(&self, position: &Position) -> Self {
+    pub fn with_obstacle_at(&self, position: &Point) -> Self {
         let mut map = self.map.clone();
-        map[position.y as usize][position.x as usize] = Object::Obstacle;
+        map.set(*position, Object::Obstacle);
 
         Self {
             map,
-            guard_position: self.guard_position.clone(),
-            guard_direction: self.guard_direction.clone(),
+            guard_position: self.guard_position,
+            guard_direction: self.guard_direction,
         }
     }
 
@@ -110,18 +81,8 @@ impl World {
             None => return self,
         };
 
-        let next_position = match guard_position.move_in_direction(guard_direction) {
-            Some(v) => v,
-            None => {
-                // guard just exited the map
-                return Self {
-                    map: self.map,
-                    guard_position: None,
-                    guard_direction: None,
-                };
-            }
-        };
-        let object_at_next_position = match self.object_at(&next_position) {
+        let next_position = *guard_position + *guard_direction;
+        let object_at_next_position = match self.map.get(next_position) {
             Some(v) => v,
             None => {
                 // guard just exited the map
@@ -134,8 +95,8 @@ impl World {
         };
 
         let (guard_position, guard_direction) = match object_at_next_position {
-            Object::Empty => (next_position, guard_direction.clone()), // continue moving in same direction
-            Object::Obstacle => (guard_position.clone(), guard_direction.turn_right()), // turn right because there is an obstacle in front of guard
+            Object::Empty => (next_position, *guard_direction), // continue moving in same direction
+            Object::Obstacle => (*guard_position, guard_direction.turn_right()), // turn right because there is an obstacle in front of guard
         };
 
         Self {
@@ -145,18 +106,17 @@ impl World {
         }
     }
 
+    #[allow(dead_code)]
     pub fn encode_to_string(&self) -> String {
-        self.map.iter()
-            .map(|row| row.iter().map(|obj| match obj {
-                Object::Empty => '.',
-                Object::Obstacle => '#',
-            }).collect::<String>())
-            .collect::<Vec<_>>().join("\n")
+        self.map.map(|obj| match obj {
+            Object::Empty => '.',
+            Object::Obstacle => '#',
+        }).to_string()
     }
 }
 
 pub struct VisitedPositions {
-    pub visited_positions: HashSet<Position>,
+    pub visited_positions: HashSet<Point>,
     pub loops: bool,
 }
 
@@ -171,10 +131,10 @@ pub fn calculate_visited_positions(mut world: World) -> VisitedPositions {
     let mut visited_positions_with_directions = HashSet::new();
 
     loop {
-        visited_positions.insert(world.guard_position.clone().unwrap());
+        visited_positions.insert(world.guard_position.unwrap());
         if !visited_positions_with_directions.insert((
-            world.guard_position.clone().unwrap(),
-            world.guard_direction.clone().unwrap(),
+            world.guard_position.unwrap(),
+            world.guard_direction.unwrap(),
         )) {
             return VisitedPositions {
                 visited_positions,
@@ -194,85 +154,54 @@ pub fn calculate_visited_positions(mut world: World) -> VisitedPositions {
     }
 }
 
-pub fn parse_world(s: &str) -> World {
-    let lines = s.lines().filter(|v| !v.is_empty()).collect::<Vec<_>>();
-
-    let mut map = Vec::new();
-    let mut guard_position = None;
-    let mut guard_direction = None;
-
-    for y in 0..lines.len() {
-        let mut row = Vec::new();
-        for x in 0..lines[0].len() {
-            let position = Position::new(x as u32, y as u32);
-
-            let object = match lines[y].chars().nth(x).unwrap() {
-                '#' => Object::Obstacle,
-                '^' => {
-                    guard_position = Some(position);
-                    guard_direction = Some(Direction::Top);
-                    Object::Empty
-                },
-                '>' => {
-                    guard_position = Some(position);
-                    guard_direction = Some(Direction::Right);
-                    Object::Empty
-                },
-                'v' => {
-                    guard_position = Some(position);
-                    guard_direction = Some(Direction::Bottom);
-                    Object::Empty
-                },
-                '<' => {
-                    guard_position = Some(position);
-                    guard_direction = Some(Direction::Left);
-                    Object::Empty
-                },
-                _ => Object::Empty
-            };
-            row.push(object);
-        }
-        map.push(row);
-    }
-
-    World::new(
-        map,
-        guard_position.expect("world without guard"),
-        guard_direction.expect("world without guard")
-    )
+pub fn parse_world(s: &str) -> Result<World, ParseError> {
+    // guard stands on an empty position
+    let cells = parse::grid_with_legend(s, &[
+        ('.', (Object::Empty, None)),
+        ('#', (Object::Obstacle, None)),
+        ('^', (Object::Empty, Some(Direction::Top))),
+        ('>', (Object::Empty, Some(Direction::Right))),
+        ('v', (Object::Empty, Some(Direction::Bottom))),
+        ('<', (Object::Empty, Some(Direction::Left))),
+    ])?;
+
+    let (guard_position, guard_direction) = cells.cells()
+        .find_map(|(position, (_, direction))| direction.map(|direction| (position, direction)))
+        .ok_or_else(|| ParseError::new(s.lines().count() + 1, 1, "expected a guard (one of '^', '>', 'v', '<')"))?;
+
+    Ok(World::new(cells.map(|(object, _)| object.clone()), guard_position, guard_direction))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        crate::examples::example_input,
+        super::*,
+    };
 
     #[test]
     fn test_example() {
-        let world = parse_world(r#"....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#..."#);
+        let world = parse_world(&example_input(6, 1)).unwrap();
         assert_eq!(calculate_visited_positions(world).total_positions(), 41);
     }
 
     #[test]
     fn test_detect_loop() {
-        let world = parse_world(r#"....#.....
-....+---+#
-....|...|.
-..#.|...|.
-....|..#|.
-....|...|.
-.#.#^---+.
-........#.
-#.........
-......#..."#);
+        // O marks the new obstruction, other symbols show the path of the guard
+        let input = example_input(6, 7).replace('O', "#").replace(['|', '-', '+'], ".");
+        let world = parse_world(&input).unwrap();
         assert_eq!(calculate_visited_positions(world).loops, true);
     }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_world("..#\n.^O\n").err().map(|err| err.to_string()),
+            Some("line 2, column 3: unexpected 'O', expected one of: '.', '#', '^', '>', 'v', '<'".to_owned())
+        );
+        assert_eq!(
+            parse_world("..#\n...\n").err(),
+            Some(ParseError::new(3, 1, "expected a guard (one of '^', '>', 'v', '<')"))
+        );
+    }
 }
@@ -1,9 +1,5 @@
-use {
-    crate::utils::download_input,
-    super::{calculate_visited_positions, parse_world},
-};
+use super::{World, calculate_visited_positions};
 
-#[allow(dead_code)]
-pub fn run() {
-    println!("result: {}", calculate_visited_positions(parse_world(&download_input(6))).total_positions());
+pub fn solve(world: &World) -> u32 {
+    calculate_visited_positions(world.clone()).total_positions()
 }
@@ -1,6 +1,28 @@
+use crate::{parse::{self, ParseError}, solution::Solution};
+
 pub mod part1;
 pub mod part2;
 
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_input(input).unwrap_or_else(|err| panic!("invalid input: {}", err))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input)
+    }
+}
+
 pub struct Equation {
     expected_result: u64,
     parts: Vec<u64>,
@@ -15,16 +37,20 @@ impl Equation {
     }
 }
 
-pub fn solve(equations: Vec<Equation>, use_concat: bool) -> u64 {
+pub fn solve(equations: &[Equation], use_concat: bool) -> u64 {
     equations
-        .into_iter()
+        .iter()
         .filter(|equation| check_equation(equation, use_concat))
         .map(|equation| equation.expected_result)
         .sum()
 }
 
 pub fn check_equation(equation: &Equation, use_concat: bool) -> bool {
-    is_true_equation(equation.expected_result, equation.parts[0], &equation.parts[1..], use_concat)
+    match equation.parts.split_first() {
+        Some((first, rest)) => is_true_equation(equation.expected_result, *first, rest, use_concat),
+        // rejected when parsing
+        None => false,
+    }
 }
 
 fn is_true_equation(target_value: u64, current_value: u64, components: &[u64], use_concat: bool) -> bool {
@@ -38,36 +64,24 @@ fn is_true_equation(target_value: u64, current_value: u64, components: &[u64], u
         || (use_concat && is_true_equation(target_value, format!("{}{}", current_value, next).parse().unwrap(), &components[1..], use_concat))
 }
 
-fn parse_input(input: String) -> Vec<Equation> {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
+fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    parse::lines(input)
         .map(|line| {
-            let spl: Vec<_> = line.split(":").collect();
-            Equation::new(
-                spl.get(0).unwrap().parse().unwrap(),
-                spl.get(1).unwrap().split(" ").into_iter()
-                    .filter(|number| !number.is_empty())
-                    .map(|number| number.trim().parse().unwrap())
-                    .collect(),
-            )
+            let (expected_result, parts) = line.key_values()?;
+            if parts.is_empty() {
+                return Err(line.error_at(line.text.len(), "equation without numbers"));
+            }
+            Ok(Equation::new(expected_result, parts))
         })
         .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
-    const EXAMPLE: &str = r#"190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20"#;
+    use {
+        crate::examples::example_input,
+        super::*,
+    };
 
     #[test]
     fn check_example_1() {
@@ -96,11 +110,27 @@ mod tests {
 
     #[test]
     fn solve_example() {
-        assert_eq!(solve(parse_input(EXAMPLE.to_owned()), false), 3749);
+        assert_eq!(solve(&parse_input(&example_input(7, 1)).unwrap(), false), 3749);
     }
 
     #[test]
     fn solve_example_with_concat() {
-        assert_eq!(solve(parse_input(EXAMPLE.to_owned()), true), 11387);
+        assert_eq!(solve(&parse_input(&example_input(7, 1)).unwrap(), true), 11387);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_input("190: 10 19\n3267:\n").err(),
+            Some(ParseError::new(2, 6, "equation without numbers"))
+        );
+        assert_eq!(
+            parse_input("190: 10 19\n3267:   \n").err(),
+            Some(ParseError::new(2, 9, "equation without numbers"))
+        );
+        assert_eq!(
+            parse_input("190 10 19\n").err().map(|err| err.to_string()),
+            Some("line 1, column 10: expected \"key: values\"".to_owned())
+        );
     }
 }
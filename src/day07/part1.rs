@@ -1,9 +1,5 @@
-use {
-    crate::utils::download_input,
-    super::{solve, parse_input},
-};
+use super::Equation;
 
-#[allow(dead_code)]
-pub fn run() {
-    println!("result: {}",  solve(parse_input(download_input(7)), false));
+pub fn solve(equations: &[Equation]) -> u64 {
+    super::solve(equations, false)
 }
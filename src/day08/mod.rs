@@ -1,48 +1,46 @@
-use rpds::{HashTrieMapSync, HashTrieSet, HashTrieSetSync};
+use {
+    rpds::{HashTrieMapSync, HashTrieSet, HashTrieSetSync},
+    crate::{grid::Grid, point::Point, solution::Solution},
+};
 
 pub mod part1;
 pub mod part2;
 
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
-struct Position {
-    // top left corner is (0, 0)
-    x: u32,
-    y: u32,
-}
+pub struct Day08;
 
-impl Position {
-    pub fn new(x: u32, y: u32) -> Self {
-        Self {
-            x,
-            y,
-        }
+impl Solution for Day08 {
+    type Input = Map;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_map(input)
     }
 
-    fn diff(&self, other: &Position) -> (i32, i32) {
-        (
-            self.x as i32 - other.x as i32,
-            self.y as i32 - other.y as i32
-        )
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input)
     }
 }
 
 #[derive(Debug)]
 pub struct Map {
-    antennas: HashTrieMapSync<char, HashTrieSetSync<Position>>,
-    rows: u32,
-    columns: u32,
+    grid: Grid<char>,
+    antennas: HashTrieMapSync<char, HashTrieSetSync<Point>>,
 }
 
 impl Map {
-    pub fn new(rows: u32, columns: u32) -> Self {
+    pub fn new(grid: Grid<char>) -> Self {
         Self {
+            grid,
             antennas: HashTrieMapSync::new_sync(),
-            rows,
-            columns,
         }
     }
 
-    fn add_antenna(self, frequency: char, position: Position) -> Self {
+    fn add_antenna(self, frequency: char, position: Point) -> Self {
         let antennas = self.antennas.insert(
             frequency,
             self.antennas.get(&frequency).cloned().unwrap_or(HashTrieSetSync::new_sync())
@@ -57,34 +55,29 @@ impl Map {
 }
 
 pub fn parse_map(s: &str) -> Map {
-    let lines = s.lines().filter(|line| !line.is_empty()).collect::<Vec<_>>();
+    let grid = Grid::parse(s, |c| c);
 
-    lines
-        .iter()
-        .enumerate()
-        .flat_map(|(y, line)| line.chars()
-            .enumerate()
-            .filter(|(_x, frequency)| frequency.is_ascii_digit() || frequency.is_ascii_alphabetic())
-            .map(move |(x, frequency)| (frequency, Position::new(x as u32, y as u32)))
-        )
+    grid
+        .cells()
+        .filter(|(_position, frequency)| frequency.is_ascii_digit() || frequency.is_ascii_alphabetic())
         .fold(
-            Map::new(lines.len() as u32, lines[0].len() as u32),
-            |map, (frequency, position)| map.add_antenna(frequency, position)
+            Map::new(grid.clone()),
+            |map, (position, frequency)| map.add_antenna(*frequency, position)
         )
 }
 
-fn is_antinode_position_for_antennas(position: &Position, antennas: &HashTrieSetSync<Position>, any_distance: bool) -> bool {
+fn is_antinode_position_for_antennas(position: &Point, antennas: &HashTrieSetSync<Point>, any_distance: bool) -> bool {
     let distances = antennas
         .iter()
-        .map(|antenna_position| position.diff(antenna_position))
-        .filter(|distance| distance.0 != 0 || distance.1 != 0)
+        .map(|antenna_position| *position - *antenna_position)
+        .filter(|distance| *distance != Point::default())
         .collect::<HashTrieSet<_>>();
 
     if !any_distance {
         // part 1
         distances
             .iter()
-            .find(|distance| distances.contains(&(distance.0 * 2, distance.1 * 2)))
+            .find(|distance| distances.contains(&(**distance * 2)))
             .is_some()
     } else {
         // part 2
@@ -96,17 +89,17 @@ fn is_antinode_position_for_antennas(position: &Position, antennas: &HashTrieSet
                 antennas.iter()
                     .filter(|other| other != antenna)
                     .find(|other| {
-                        let a = antenna.y as i32 - other.y as i32;
-                        let b = other.x as i32 - antenna.x as i32;
-                        let c = (antenna.x as i32) * (other.y as i32) - (other.x as i32) * (antenna.y as i32);
+                        let a = antenna.y - other.y;
+                        let b = other.x - antenna.x;
+                        let c = antenna.x * other.y - other.x * antenna.y;
 
-                        a * position.x as i32 + b * position.y as i32 + c == 0
+                        a * position.x + b * position.y + c == 0
                     }).is_some()
             }).is_some()
     }
 }
 
-fn is_antinode_position(position: &Position, map: &Map, any_distance: bool) -> bool {
+fn is_antinode_position(position: &Point, map: &Map, any_distance: bool) -> bool {
     map.antennas
         .iter()
         .find(|(_frequency, antennas)| is_antinode_position_for_antennas(position, antennas, any_distance))
@@ -114,9 +107,8 @@ fn is_antinode_position(position: &Position, map: &Map, any_distance: bool) -> b
 }
 
 fn solve(map: &Map, any_distance: bool) -> u32 {
-    (0..map.rows)
-        .into_iter()
-        .flat_map(|y| (0..map.columns).into_iter().map(move |x| Position::new(x, y)))
+    map.grid
+        .points()
         .filter(|position| is_antinode_position(position, map, any_distance))
         .count() as u32
 }
@@ -125,44 +117,16 @@ fn solve(map: &Map, any_distance: bool) -> u32 {
 mod tests {
     use {
         lazy_static::lazy_static,
+        crate::examples::example_input,
         super::*,
     };
 
     lazy_static! {
-        static ref EXAMPLE_MAP: Map = parse_map(r#"............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............"#);
-
-        static ref EXAMPLE_MAP_1: Map = parse_map(r#"..........
-..........
-..........
-....a.....
-..........
-.....a....
-..........
-..........
-..........
-.........."#);
-
-        static ref EXAMPLE_MAP_PART2: Map = parse_map(r#"T.........
-...T......
-.T........
-..........
-..........
-..........
-..........
-..........
-..........
-.........."#);
+        static ref EXAMPLE_MAP: Map = parse_map(&example_input(8, 1));
+
+        static ref EXAMPLE_MAP_1: Map = parse_map(&example_input(8, 2));
+
+        static ref EXAMPLE_MAP_PART2: Map = parse_map(&example_input(8, 6));
     }
 
     #[test]
@@ -1,9 +1,5 @@
-use {
-    crate::utils::download_input,
-    super::{solve, parse_map},
-};
+use super::Map;
 
-#[allow(dead_code)]
-pub fn run() {
-    println!("result: {}", solve(&parse_map(&download_input(8)), true));
+pub fn solve(map: &Map) -> u32 {
+    super::solve(map, true)
 }
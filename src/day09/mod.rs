@@ -1,6 +1,30 @@
+#![allow(clippy::double_ended_iterator_last, clippy::filter_next, clippy::match_like_matches_macro, clippy::needless_range_loop, clippy::useless_conversion, clippy::while_let_loop)]
+
+use crate::solution::Solution;
+
 pub mod part1;
 pub mod part2;
 
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = DiskMap;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_disk_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Block {
     Free,
@@ -25,6 +49,7 @@ impl Block {
     }
 }
 
+#[derive(Clone)]
 pub struct DiskMap {
     blocks: Vec<Block>,
     first_empty_position: u32,
@@ -32,6 +57,7 @@ pub struct DiskMap {
 }
 
 impl DiskMap {
+    #[allow(dead_code)]
     pub fn new() -> Self {
         Self {
             blocks: Vec::new(),
@@ -48,6 +74,7 @@ impl DiskMap {
         }
     }
 
+    #[allow(dead_code)]
     pub fn add_block(&mut self, block: Block) {
         self.blocks.push(block);
     }
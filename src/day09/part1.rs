@@ -1,25 +1,20 @@
-use {
-    crate::utils::download_input,
-    super::parse_disk_map,
-};
+use super::DiskMap;
 
-#[allow(dead_code)]
-pub fn run() {
-    println!("result: {}", solve(&download_input(9)));
-}
-
-fn solve(disk_map: &str) -> u64 {
-    let mut disk_map = parse_disk_map(disk_map);
+pub fn solve(disk_map: &DiskMap) -> u64 {
+    let mut disk_map = disk_map.clone();
     disk_map.defragment();
     disk_map.checksum()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use super::{
+        *,
+        super::parse_disk_map,
+    };
 
     #[test]
     fn test_example() {
-        assert_eq!(solve("2333133121414131402"), 1928);
+        assert_eq!(solve(&parse_disk_map("2333133121414131402")), 1928);
     }
 }
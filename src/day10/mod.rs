@@ -1,253 +1,135 @@
-use std::collections::{HashMap, HashSet};
+use {
+    crate::{grid::Grid, memo::Memo, point::Point, search, solution::Solution},
+};
 
 const MAX_HEIGHT: u8 = 9;
+// '.' in examples, no trail goes through it
+const IMPASSABLE: u8 = u8::MAX;
 
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-pub struct Position {
-    x: u32,
-    y: u32,
-}
+pub struct Day10;
 
-impl Position {
-    pub fn new(x: u32, y: u32) -> Self {
-        Self {
-            x,
-            y,
-        }
+impl Solution for Day10 {
+    type Input = Map;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_map(input)
     }
 
-    pub fn neighbours(&self) -> Vec<Position> {
-        vec![
-            if self.y > 0 {
-                Some(Position {
-                    x: self.x,
-                    y: self.y - 1,
-                })
-            } else {
-                None
-            },
-            Some(Position {
-                x: self.x + 1,
-                y: self.y,
-            }),
-            Some(Position {
-                x: self.x,
-                y: self.y + 1,
-            }),
-            if self.x > 0 {
-                Some(Position {
-                    x: self.x - 1,
-                    y: self.y,
-                })
-            } else {
-                None
-            }
-        ].into_iter().filter_map(|v| v).collect()
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        part1::solve(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        part2::solve(input)
     }
 }
 
 pub struct Map {
-    // top left is (0, 0).
-    map: Vec<Vec<u8>>,
-    reachable_heights: HashMap<Position, HashSet<Position>>,
-    trails: HashMap<Position, HashSet<Vec<Position>>>,
+    map: Grid<u8>,
 }
 
 impl Map {
+    #[allow(dead_code)]
     pub fn new() -> Self {
-        Self {
-            map: Vec::new(),
-            reachable_heights: HashMap::new(),
-            trails: HashMap::new(),
-        }
+        Self::from_grid(Grid::from_rows(Vec::new()))
     }
 
-    fn from_map_vec(map: Vec<Vec<u8>>) -> Self {
+    fn from_grid(map: Grid<u8>) -> Self {
         Self {
             map,
-            reachable_heights: HashMap::new(),
-            trails: HashMap::new(),
         }
     }
 
-    pub fn at(&self, position: &Position) -> Option<u8> {
-        self.map.get(position.x as usize).and_then(|v| v.get(position.y as usize)).map(|v| *v)
+    pub fn at(&self, position: &Point) -> Option<u8> {
+        self.map.get(*position).copied()
     }
 
-    pub fn reachable_heights_from_position(&mut self, position: &Position, skip_positions: HashSet<Position>) -> HashSet<Position> {
-        if skip_positions.contains(position) {
-            return HashSet::new(); // if already visited position, return empty set.
-        }
-
-        let self_height = match self.at(position) {
-            Some(v) => v,
-            None => return HashSet::new(), // if invalid position, return empty set
-        };
-
-        // if this position is top, return itself
-        if self_height == MAX_HEIGHT {
-            let mut self_set = HashSet::new();
-            self_set.insert(position.clone());
-            return self_set;
-        }
-
-        // if have positions memoized, return them
-        if let Some(heights) = self.reachable_heights.get(position) {
-            return heights.clone();
-        }
-
-        // else, let's compute based on neighbours
-        let mut visited = skip_positions.clone();
-        visited.insert(position.clone());
-
-        let mut result = HashSet::new();
-        for other in position.neighbours() {
-            let other_height = match self.at(&other) {
-                Some(v) => v,
-                None => continue,
-            };
-
-            if other_height != self_height + 1 {
-                continue;
-            }
-
-            for height in self.reachable_heights_from_position(&other, visited.clone()) {
-                result.insert(height);
-            }
-        }
-
-        self.reachable_heights.insert(position.clone(), result.clone());
-
-        result
+    fn trailheads(&self) -> Vec<Point> {
+        self.map.cells().filter(|(_, height)| **height == 0).map(|(position, _)| position).collect()
     }
 
-    pub fn total_trails_from_position(&mut self, position: &Position, skip_positions: HashSet<Position>) -> HashSet<Vec<Position>> {
-        if skip_positions.contains(position) {
-            return HashSet::new(); // if already visited position, return empty set.
-        }
-
-        let self_height = match self.at(position) {
+    // neighbours that are exactly one higher, the only ones a trail can continue to
+    fn uphill_neighbours(&self, position: &Point) -> Vec<Point> {
+        let height = match self.at(position) {
             Some(v) => v,
-            None => return HashSet::new(), // if invalid position, return empty set
+            None => return Vec::new(),
         };
 
-        // if this position is top, return itself
-        if self_height == MAX_HEIGHT {
-            let mut self_set = HashSet::new();
-            self_set.insert(vec![position.clone()]);
-            return self_set;
-        }
-
-        // if have trails memoized, return them
-        if let Some(trails) = self.trails.get(position) {
-            return trails.clone();
-        }
-
-        // else, let's compute based on neighbours
-        let mut visited = skip_positions.clone();
-        visited.insert(position.clone());
-
-        let mut result = HashSet::new();
-
-        for other in position.neighbours() {
-            let other_height = match self.at(&other) {
-                Some(v) => v,
-                None => continue,
-            };
+        self.map.neighbours4(*position)
+            .filter(|other| self.at(other) == height.checked_add(1))
+            .collect()
+    }
 
-            if other_height != self_height + 1 {
-                continue;
-            }
+    pub fn reachable_heights_from_position(&self, position: &Point) -> usize {
+        search::bfs(*position, |position| self.uphill_neighbours(position))
+            .visited
+            .iter()
+            .filter(|position| self.at(position) == Some(MAX_HEIGHT))
+            .count()
+    }
 
-            for mut trail in self.total_trails_from_position(&other, visited.clone()) {
-                trail.insert(0, position.clone());
-                result.insert(trail);
-            }
+    // heights only go up along a trail, so trails can't loop and there is no need to track visited positions
+    pub fn total_trails_from_position(&self, recurse: &mut dyn FnMut(Point) -> u32, position: &Point) -> u32 {
+        // if this position is top, it is the end of a single trail
+        if self.at(position) == Some(MAX_HEIGHT) {
+            return 1;
         }
 
-        self.trails.insert(position.clone(), result.clone());
-
-        result
+        self.uphill_neighbours(position)
+            .into_iter()
+            .map(recurse)
+            .sum()
     }
 }
 
 fn parse_map(input: &str) -> Map {
-    Map::from_map_vec(input.lines()
-        .into_iter()
-        .filter(|line| !line.is_empty())
-        .map(|line| line.chars().into_iter().filter_map(|v| v.to_digit(10).map(|v| v as u8)).collect())
-        .collect())
+    Map::from_grid(Grid::parse(input, |v| v.to_digit(10).map(|v| v as u8).unwrap_or(IMPASSABLE)))
 }
 
 pub mod part1 {
-    use {
-        crate::utils::download_input,
-        super::*,
-    };
-
-    #[allow(dead_code)]
-    pub fn run() {
-        println!("result: {}", solve(&download_input(10)));
-    }
-
-    pub fn solve(input: &str) -> u32 {
-        let mut map = parse_map(input);
-        let mut total = 0;
-        for y in 0..map.map.len() {
-            for x in 0..map.map[y].len() {
-                let position = Position::new(x as u32, y as u32);
-                if map.at(&position).unwrap() == 0 {
-                    total += map.reachable_heights_from_position(&position, HashSet::new()).len() as u32;
-                }
-            }
-        }
+    use super::*;
 
-        total
+    pub fn solve(map: &Map) -> u32 {
+        map.trailheads()
+            .iter()
+            .map(|position| map.reachable_heights_from_position(position) as u32)
+            .sum()
     }
 }
 
 pub mod part2 {
-    use {
-        crate::utils::download_input,
-        super::*,
-    };
-
-    #[allow(dead_code)]
-    pub fn run() {
-        println!("result: {}", solve(&download_input(10)));
-    }
+    use super::*;
 
-    pub fn solve(input: &str) -> u32 {
-        let mut map = parse_map(input);
-        let mut total = 0;
-        for y in 0..map.map.len() {
-            for x in 0..map.map[y].len() {
-                let position = Position::new(x as u32, y as u32);
-                if map.at(&position).unwrap() == 0 {
-                    total += map.total_trails_from_position(&position, HashSet::new()).len() as u32;
-                }
-            }
-        }
+    pub fn solve(map: &Map) -> u32 {
+        let mut memo = Memo::new();
+        let total_trails = |recurse: &mut dyn FnMut(Point) -> u32, position: &Point| map.total_trails_from_position(recurse, position);
 
-        total
+        map.trailheads()
+            .into_iter()
+            .map(|position| memo.solve(position, &total_trails))
+            .sum()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use {
+        crate::examples::example_input,
+        super::*,
+    };
+
+    #[test]
+    fn test_impassable_positions() {
+        assert_eq!(part1::solve(&parse_map(&example_input(10, 2))), 2);
+        assert_eq!(part1::solve(&parse_map(&example_input(10, 3))), 4);
+    }
 
     #[test]
     fn test_example_part1() {
         assert_eq!(
-            part1::solve(r#"89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732"#),
+            part1::solve(&parse_map(&example_input(10, 5))),
             36
         );
     }
@@ -255,14 +137,7 @@ mod tests {
     #[test]
     fn test_example_part2() {
         assert_eq!(
-            part2::solve(r#"89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732"#),
+            part2::solve(&parse_map(&example_input(10, 5))),
             81
         );
     }
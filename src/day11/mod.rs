@@ -1,17 +1,36 @@
 use {
-    std::collections::HashMap,
     num_bigint::BigUint,
     num_traits::{ops::checked::CheckedMul, FromPrimitive},
+    crate::{memo::Memo, solution::Solution},
 };
 
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Stones;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        parse_stones(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        solve(input, 25)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        solve(input, 75)
+    }
+}
+
 #[derive(Eq, PartialEq, Clone)]
-struct Stone {
+pub struct Stone {
     number: BigUint,
 }
 
-struct Stones {
+pub struct Stones {
     stones: Vec<Stone>,
-    stones_after_blinks: HashMap<(BigUint, u32), u64>,
 }
 
 impl Stone {
@@ -26,48 +45,35 @@ impl Stones {
     pub fn new(stones: Vec<Stone>) -> Self {
         Self {
             stones,
-            stones_after_blinks: HashMap::new(),
         }
     }
 
-    fn stones_after_blinks(&mut self, blinks: u32) -> u64 {
-        let mut result = 0;
-        for stone in &self.stones.clone() {
-            result += self.stones_after_blinks_for_stone(stone, blinks);
-        }
-        result
+    fn stones_after_blinks(&self, blinks: u32) -> u64 {
+        let mut memo = Memo::new();
+        self.stones
+            .iter()
+            .map(|stone| memo.solve((stone.number.clone(), blinks), &stones_after_blinks_for_stone))
+            .sum()
     }
+}
 
-    fn stones_after_blinks_for_stone(&mut self, stone: &Stone, blinks: u32) -> u64 {
-        if blinks == 0 {
-            return 1; // if no more blinks, then we are left with just this one stone
-        }
-
-        // if there is a memoized result, return it.
-        if let Some(stones) = self.stones_after_blinks.get(&(stone.number.clone(), blinks)) {
-            return *stones;
-        }
+// number of stones a stone with given number turns into after given number of blinks
+fn stones_after_blinks_for_stone(recurse: &mut dyn FnMut((BigUint, u32)) -> u64, (number, blinks): &(BigUint, u32)) -> u64 {
+    if *blinks == 0 {
+        return 1; // if no more blinks, then we are left with just this one stone
+    }
 
-        let result = if stone.number == BigUint::ZERO {
-            self.stones_after_blinks_for_stone(&Stone::new(BigUint::from_u32(1).unwrap()), blinks - 1)
+    if *number == BigUint::ZERO {
+        recurse((BigUint::from_u32(1).unwrap(), blinks - 1))
+    } else {
+        let digits = number.to_radix_be(10);
+        if digits.len() % 2 == 0 {
+            recurse((BigUint::from_radix_be(&digits[0..digits.len() / 2], 10).unwrap(), blinks - 1))
+                .checked_add(recurse((BigUint::from_radix_be(&digits[digits.len()/2..], 10).unwrap(), blinks - 1)))
+                .unwrap()
         } else {
-            let digits = stone.number.to_radix_be(10);
-            if digits.len() % 2 == 0 {
-                self.stones_after_blinks_for_stone(
-                    &Stone::new(BigUint::from_radix_be(&digits[0..digits.len() / 2], 10).unwrap()),
-                    blinks - 1
-                ).checked_add(self.stones_after_blinks_for_stone(
-                    &Stone::new(BigUint::from_radix_be(&digits[digits.len()/2..], 10).unwrap()),
-                    blinks - 1
-                )).unwrap()
-            } else {
-                self.stones_after_blinks_for_stone(&Stone::new(stone.number.checked_mul(&BigUint::from_u32(2024).unwrap()).unwrap()), blinks - 1)
-            }
-        };
-
-        self.stones_after_blinks.insert((stone.number.clone(), blinks), result);
-
-        result
+            recurse((number.checked_mul(&BigUint::from_u32(2024).unwrap()).unwrap(), blinks - 1))
+        }
     }
 }
 
@@ -80,52 +86,34 @@ fn parse_stones(input: &str) -> Stones {
     )
 }
 
-fn solve(input: &str, blinks: u32) -> u64 {
-    parse_stones(input).stones_after_blinks(blinks)
-}
-
-pub mod part1 {
-    use {
-        crate::utils::download_input,
-        super::solve,
-    };
-
-    #[allow(dead_code)]
-    pub fn run() {
-        println!("result: {}", solve(&download_input(11), 25));
-    }
-}
-
-pub mod part2 {
-    use {
-        crate::utils::download_input,
-        super::solve,
-    };
-
-    #[allow(dead_code)]
-    pub fn run() {
-        println!("result: {}", solve(&download_input(11), 75));
-    }
+fn solve(stones: &Stones, blinks: u32) -> u64 {
+    stones.stones_after_blinks(blinks)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::utils::download_input;
-
-    use super::*;
+    use {
+        crate::{answers::AnswerStore, utils::cached_input},
+        super::*,
+    };
 
     #[test]
     fn test_example() {
         assert_eq!(
-            solve("125 17", 25),
+            solve(&parse_stones("125 17"), 25),
             55312
         );
     }
 
     #[test]
     fn test_result_part1() {
-        assert!(
-            solve(&download_input(11), 25) > 166011
-        )
+        let input = match cached_input(11).unwrap() {
+            Some(v) => v,
+            None => return,
+        };
+
+        let result = solve(&parse_stones(&input), 25).to_string();
+        let check = AnswerStore::load_default().unwrap().check(11, 1, &result);
+        assert!(!check.is_known_wrong(), "{}: {}", result, check);
     }
 }
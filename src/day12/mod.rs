@@ -1,53 +1,31 @@
-use std::collections::HashSet;
+use {
+    std::collections::HashSet,
+    crate::{grid::Grid, point::{Direction, Point}, search, solution::Solution},
+};
 
-#[derive(Eq, PartialEq, Hash, Clone, Debug)]
-struct Position {
-    x: i32,
-    y: i32,
-}
+pub struct Day12;
 
-impl Position {
-    fn new(x: i32, y: i32) -> Self {
-        Self {
-            x,
-            y,
-        }
-    }
+impl Solution for Day12 {
+    type Input = World;
+    type Output1 = u32;
+    type Output2 = u32;
 
-    fn neighbors(&self) -> Vec<Position> {
-        vec![
-            Position::new(self.x - 1, self.y),
-            Position::new(self.x + 1, self.y),
-            Position::new(self.x, self.y - 1),
-            Position::new(self.x, self.y + 1),
-        ]
+    fn parse(input: &str) -> Self::Input {
+        parse_world(input)
     }
 
-    fn neighbor_with_direction(&self, direction: &Direction) -> Position {
-        match direction {
-            Direction::Top => Position::new(self.x, self.y - 1),
-            Direction::Right => Position::new(self.x + 1, self.y),
-            Direction::Bottom => Position::new(self.x, self.y + 1),
-            Direction::Left => Position::new(self.x - 1, self.y),
-        }
+    fn part1(input: &Self::Input) -> Self::Output1 {
+        input.total_cost(false)
     }
-}
 
-#[derive(Eq, PartialEq, Clone, Debug, Hash)]
-enum Direction {
-    Top,
-    Right,
-    Bottom,
-    Left,
+    fn part2(input: &Self::Input) -> Self::Output2 {
+        input.total_cost(true)
+    }
 }
 
-impl Direction {
-    fn perpendicular(&self) -> Vec<Self> {
-        match &self {
-            Self::Top | Self::Bottom => vec![Self::Right, Self::Left],
-            Self::Left | Self::Right => vec![Self::Top, Self::Bottom],
-        }
-    }
+// including the ones outside of the map
+fn neighbors(position: &Point) -> [Point; 4] {
+    Direction::ALL.map(|direction| *position + direction)
 }
 
 #[derive(Clone, Debug)]
@@ -63,83 +41,42 @@ impl Plot {
     }
 }
 
-struct World {
-    // (0, 0) is top left
-    map: Vec<Vec<Plot>>,
+pub struct World {
+    map: Grid<Plot>,
 }
 
 impl World {
-    fn new(map: Vec<Vec<Plot>>) -> Self {
+    fn new(map: Grid<Plot>) -> Self {
         Self {
             map,
         }
     }
 
-    fn at(&self, position: &Position) -> Option<&Plot> {
-        if position.x < 0 || position.y < 0 {
-            return None;
-        }
-
-        self.map.get(position.y as usize)
-            .and_then(|row| row.get(position.x as usize))
-    }
-
     fn regions(&self) -> Vec<Region> {
         let mut result = Vec::new();
 
         let mut explored_positions = HashSet::new();
 
-        for y in 0..self.map.len() {
-            for x in 0..self.map[y].len() {
-                let position = Position::new(x as i32, y as i32);
-                if explored_positions.contains(&position) {
-                    continue;
-                }
-
-                // create a new region
-                let mut region_positions = HashSet::new();
-                let plot = self.at(&position).unwrap();
-                let region_plant_type = plot.plant_type.clone();
+        for (position, plot) in self.map.cells() {
+            if explored_positions.contains(&position) {
+                continue;
+            }
 
-                self.explore_region(region_plant_type, &position, &mut region_positions, &mut explored_positions);
+            // create a new region
+            let region_positions = self.explore_region(plot.plant_type, position);
+            explored_positions.extend(region_positions.iter().copied());
 
-                result.push(Region::new(plot.clone(), region_positions.into_iter().collect()));
-            }
+            result.push(Region::new(plot.clone(), region_positions));
         }
 
         result
     }
 
-    fn explore_region(&self, plant_type: char, position: &Position, region_positions: &mut HashSet<Position>, explored_positions: &mut HashSet<Position>) {
-        if explored_positions.contains(position) {
-            // already explored
-            return;
-        }
-
-        if region_positions.contains(position) {
-            // already explored
-            return;
-        }
-
-        let plot = match self.at(position) {
-            Some(v) => v,
-            None => {
-                // invalid position
-                return;
-            }
-        };
-
-        if plot.plant_type != plant_type {
-            // this plant type does not belong to this region
-            return;
-        }
-
-        region_positions.insert(position.clone());
-        explored_positions.insert(position.clone());
-
-        position.neighbors()
-            .into_iter()
-            .for_each(|position| self.explore_region(plant_type, &position, region_positions, explored_positions));
+    // all positions with the same plant type that are connected to given position
+    fn explore_region(&self, plant_type: char, position: Point) -> Vec<Point> {
+        search::dfs(position, |position| self.map.neighbours4(*position)
+            .filter(move |other| self.map.get(*other).is_some_and(|plot| plot.plant_type == plant_type)))
+            .visited
     }
 
     fn total_cost(&self, use_sides: bool) -> u32 {
@@ -150,11 +87,11 @@ impl World {
 #[derive(Debug)]
 struct Region {
     _plot: Plot,
-    positions: Vec<Position>,
+    positions: Vec<Point>,
 }
 
 impl Region {
-    fn new(plot: Plot, positions: Vec<Position>) -> Self {
+    fn new(plot: Plot, positions: Vec<Point>) -> Self {
         Self {
             _plot: plot,
             positions,
@@ -167,10 +104,10 @@ impl Region {
 
     fn perimeter(&self) -> u32 {
         let mut total_perimiter = 0;
-        let positions_set = self.positions.iter().cloned().collect::<HashSet<Position>>();
+        let positions_set = self.positions.iter().cloned().collect::<HashSet<Point>>();
 
         for position in &self.positions {
-            for neighbor in position.neighbors() {
+            for neighbor in neighbors(position) {
                 if positions_set.contains(&neighbor) {
                     continue;
                 }
@@ -182,24 +119,17 @@ impl Region {
     }
 
     fn sides(&self) -> u32 {
-        let positions_set = self.positions.iter().cloned().collect::<HashSet<Position>>();
-        let directions = [
-            Direction::Top,
-            Direction::Right,
-            Direction::Bottom,
-            Direction::Left,
-        ];
-
+        let positions_set = self.positions.iter().cloned().collect::<HashSet<Point>>();
         let mut edges = Vec::new();
 
         for position in &self.positions {
-            directions.iter()
-                .filter(|direction| !positions_set.contains(&position.neighbor_with_direction(direction)))
-                .for_each(|direction| edges.push((position.clone(), direction.clone())));
+            Direction::ALL.iter()
+                .filter(|direction| !positions_set.contains(&(*position + **direction)))
+                .for_each(|direction| edges.push((*position, *direction)));
         }
 
         let edges_set = edges.iter().cloned().collect::<HashSet<_>>();
-        let mut visited_edges: HashSet<(Position, Direction)> = HashSet::new();
+        let mut visited_edges: HashSet<(Point, Direction)> = HashSet::new();
 
         let mut sides = 0;
         for edge in edges {
@@ -208,10 +138,10 @@ impl Region {
             }
 
             for direction in edge.1.perpendicular() {
-                let mut position = edge.0.clone();
+                let mut position = edge.0;
                 loop {
-                    position = position.neighbor_with_direction(&direction);
-                    let new_edge = (position.clone(), edge.1.clone());
+                    position += direction.delta();
+                    let new_edge = (position, edge.1);
                     if !edges_set.contains(&new_edge) {
                         break;
                     }
@@ -235,91 +165,43 @@ impl Region {
 }
 
 fn parse_world(s: &str) -> World {
-    World::new(s.lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| line.chars().into_iter().map(|c| Plot::new(c)).collect())
-        .collect())
-}
-
-pub mod part1 {
-    use {
-        crate::utils::download_input,
-        super::*,
-    };
-
-    #[allow(dead_code)]
-    pub fn run() {
-        println!("result: {}", parse_world(&download_input(12)).total_cost(false));
-    }
+    World::new(Grid::parse(s, Plot::new))
 }
 
-pub mod part2 {
+#[cfg(test)]
+mod tests {
     use {
-        crate::utils::download_input,
+        crate::examples::example_input,
         super::*,
     };
 
-    #[allow(dead_code)]
-    pub fn run() {
-        println!("result: {}", parse_world(&download_input(12)).total_cost(true));
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
     #[test]
     fn simple_example() {
-        let world = parse_world("AAAA
-BBCD
-BBCC
-EEEC");
+        let world = parse_world(&example_input(12, 1));
         assert_eq!(world.total_cost(false), 140);
     }
 
     #[test]
     fn example() {
-        let world = parse_world("RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE");
+        let world = parse_world(&example_input(12, 3));
         assert_eq!(world.total_cost(false), 1930);
     }
 
     #[test]
     fn simple_example_with_sides() {
-        let world = parse_world("AAAA
-BBCD
-BBCC
-EEEC");
+        let world = parse_world(&example_input(12, 1));
         assert_eq!(world.total_cost(true), 80);
     }
 
     #[test]
     fn example_with_sides_2() {
-        let world = parse_world("EEEEE
-EXXXX
-EEEEE
-EXXXX
-EEEEE");
+        let world = parse_world(&example_input(12, 4));
         assert_eq!(world.total_cost(true), 236);
     }
 
     #[test]
     fn example_with_sides_3() {
-        let world = parse_world("AAAAAA
-AAABBA
-AAABBA
-ABBAAA
-ABBAAA
-AAAAAA");
+        let world = parse_world(&example_input(12, 5));
         assert_eq!(world.total_cost(true), 368);
     }
 }
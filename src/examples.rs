@@ -0,0 +1,248 @@
+use {
+    std::{fs::{create_dir_all, write, exists}, io, path::{Path, PathBuf}},
+    crate::{
+        config::{self, ConfigError},
+        http::{self, HttpClient},
+        session,
+    },
+};
+
+pub const FIXTURES_DIR: &str = "fixtures";
+
+#[derive(Debug)]
+pub enum ExamplesError {
+    Offline,
+    Config(ConfigError),
+    Request(reqwest::Error),
+    Http {
+        status: u16,
+    },
+    PuzzleNotYetUnlocked {
+        day: u32,
+    },
+    NoExamples {
+        day: u32,
+    },
+    FixtureExists(PathBuf),
+    Fixtures(io::Error),
+}
+
+impl std::fmt::Display for ExamplesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Offline => write!(f, "puzzle description can not be downloaded in offline mode"),
+            Self::Config(err) => err.fmt(f),
+            Self::Request(err) => write!(f, "failed to download puzzle description: {}", err),
+            Self::Http { status } => write!(f, "advent of code website responded with status {}", status),
+            Self::PuzzleNotYetUnlocked { day } => write!(f, "puzzle for day {} is not unlocked yet", day),
+            Self::NoExamples { day } => write!(f, "no examples found in puzzle description for day {}", day),
+            Self::FixtureExists(path) => write!(f, "fixture {} already exists (use --force to overwrite)", path.display()),
+            Self::Fixtures(err) => write!(f, "failed to write fixtures: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ExamplesError {}
+
+impl From<ConfigError> for ExamplesError {
+    fn from(err: ConfigError) -> Self {
+        Self::Config(err)
+    }
+}
+
+impl From<reqwest::Error> for ExamplesError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Request(err)
+    }
+}
+
+impl From<io::Error> for ExamplesError {
+    fn from(err: io::Error) -> Self {
+        Self::Fixtures(err)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Examples {
+    // contents of <pre><code> blocks, in order of appearance
+    pub inputs: Vec<String>,
+    // contents of <code><em> elements, the expected answers are among them
+    pub answers: Vec<String>,
+}
+
+// downloads puzzle description for a day and saves its examples as test fixtures.
+pub fn fetch_examples(day: u32, force: bool) -> Result<Vec<PathBuf>, ExamplesError> {
+    if config::get().offline {
+        return Err(ExamplesError::Offline);
+    }
+
+    // part 2 is only visible when logged in, part 1 is public
+    let session = session::load().ok().map(|(session, _source)| session);
+
+    println!("downloading puzzle description from advent of code website");
+    let html = fetch_puzzle_page(http::client()?, session.as_deref(), day)?;
+
+    let examples = extract_examples(&html);
+    if examples.inputs.is_empty() {
+        return Err(ExamplesError::NoExamples { day });
+    }
+
+    let files = save_fixtures(Path::new(FIXTURES_DIR), day, &examples, force)?;
+    // answers are not saved, they can't be matched to examples reliably and tests state them explicitly
+    if !examples.answers.is_empty() {
+        println!("expected answers are among: {}", examples.answers.join(", "));
+    }
+
+    Ok(files)
+}
+
+pub fn fetch_puzzle_page(client: &HttpClient, session: Option<&str>, day: u32) -> Result<String, ExamplesError> {
+    let response = client.get(&client.config().day_url(day), session)?;
+
+    match response.status {
+        200 => Ok(response.body),
+        404 => Err(ExamplesError::PuzzleNotYetUnlocked { day }),
+        status => Err(ExamplesError::Http { status }),
+    }
+}
+
+pub fn extract_examples(html: &str) -> Examples {
+    Examples {
+        inputs: elements_between(html, "<pre><code>", "</code></pre>")
+            .map(|block| {
+                let mut input = decode_entities(&strip_tags(block));
+                if !input.ends_with('\n') {
+                    input.push('\n');
+                }
+                input
+            })
+            .collect(),
+        answers: elements_between(html, "<code><em>", "</em></code>")
+            .map(|answer| decode_entities(&strip_tags(answer)))
+            .collect(),
+    }
+}
+
+fn elements_between<'a>(html: &'a str, start: &'a str, end: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let content_start = rest.find(start)? + start.len();
+        let content_end = content_start + rest[content_start..].find(end)?;
+        let content = &rest[content_start..content_end];
+        rest = &rest[content_end + end.len()..];
+        Some(content)
+    })
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut inside_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => inside_tag = true,
+            '>' => inside_tag = false,
+            _ if !inside_tag => text.push(c),
+            _ => {},
+        }
+    }
+    text
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn day_fixtures_dir(fixtures_dir: &Path, day: u32) -> PathBuf {
+    fixtures_dir.join(format!("day{:02}", day))
+}
+
+fn example_path(fixtures_dir: &Path, day: u32, example: usize) -> PathBuf {
+    day_fixtures_dir(fixtures_dir, day).join(format!("example_{}.txt", example))
+}
+
+// writes "dayNN/example_<n>.txt" for every example.
+fn save_fixtures(fixtures_dir: &Path, day: u32, examples: &Examples, force: bool) -> Result<Vec<PathBuf>, ExamplesError> {
+    let files = examples.inputs.iter()
+        .enumerate()
+        .map(|(index, input)| (example_path(fixtures_dir, day, index + 1), input.clone()))
+        .collect::<Vec<_>>();
+
+    if !force {
+        for (path, _) in &files {
+            if exists(path)? {
+                return Err(ExamplesError::FixtureExists(path.clone()));
+            }
+        }
+    }
+
+    create_dir_all(day_fixtures_dir(fixtures_dir, day))?;
+    for (path, content) in &files {
+        write(path, content)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+// example input saved in fixtures, for use in tests. Examples are numbered from 1 in order of appearance in
+// puzzle description, like fetch_examples saves them.
+#[cfg(test)]
+pub fn example_input(day: u32, example: usize) -> String {
+    let path = example_path(&Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURES_DIR), day, example);
+    std::fs::read_to_string(&path).unwrap_or_else(|err| panic!("failed to read fixture {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{http::test_client, test_server::TestServer},
+        super::*,
+    };
+
+    const PUZZLE_PAGE: &str = "<main><article class=\"day-desc\"><h2>--- Day 8: Resonant Collinearity ---</h2>\
+        <p>For example:</p><pre><code>..........\n...#......\n....a.....\n..........\n</code></pre>\
+        <p>Antennas with <code>&lt;</code> frequency:</p><pre><code>..<em>#</em>..\n.&amp;..\n</code></pre>\
+        <p>The map contains <code><em>14</em></code> unique locations.</p></article>\
+        <article class=\"day-desc\"><p>In this example, there are <code><em>34</em></code> locations.</p></article></main>";
+
+    #[test]
+    fn test_extract_examples() {
+        assert_eq!(extract_examples(PUZZLE_PAGE), Examples {
+            inputs: vec![
+                "..........\n...#......\n....a.....\n..........\n".to_owned(),
+                "..#..\n.&..\n".to_owned(),
+            ],
+            answers: vec!["14".to_owned(), "34".to_owned()],
+        });
+    }
+
+    #[test]
+    fn test_fetch_puzzle_page() {
+        let server = TestServer::start(vec![(200, PUZZLE_PAGE)]);
+        assert_eq!(fetch_puzzle_page(&test_client(&server.base_url), Some("test-session"), 8).unwrap(), PUZZLE_PAGE);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].path, "/2024/day/8");
+        assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    }
+
+    #[test]
+    fn test_save_fixtures() {
+        let fixtures_dir = std::env::temp_dir().join(format!("aoc-fixtures-{}", std::process::id()));
+        let examples = extract_examples(PUZZLE_PAGE);
+
+        let files = save_fixtures(&fixtures_dir, 8, &examples, false).unwrap();
+        let overwrite = save_fixtures(&fixtures_dir, 8, &examples, false);
+        let answers_saved = exists(fixtures_dir.join("day08/answers.txt")).unwrap();
+        let example = std::fs::read_to_string(fixtures_dir.join("day08/example_2.txt")).unwrap();
+        std::fs::remove_dir_all(&fixtures_dir).unwrap();
+
+        assert_eq!(files.len(), 2);
+        assert!(matches!(overwrite, Err(ExamplesError::FixtureExists(_))));
+        assert!(!answers_saved);
+        assert_eq!(example, "..#..\n.&..\n");
+    }
+}
@@ -0,0 +1,190 @@
+use {
+    std::fmt,
+    crate::point::{Direction, Point},
+};
+
+const DIAGONALS: [Point; 4] = [Point::new(1, -1), Point::new(1, 1), Point::new(-1, 1), Point::new(-1, -1)];
+
+// cells are stored row by row
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // panics if rows have different lengths
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        let height = rows.len();
+
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            assert_eq!(row.len(), width, "row {} of grid has {} cells, expected {}", y, row.len(), width);
+            cells.extend(row);
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // one row per non-empty line, one cell per char
+    pub fn parse(s: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(s.lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.chars().map(&mut cell).collect())
+            .collect())
+    }
+
+    #[allow(dead_code)]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[allow(dead_code)]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.index(point).is_some()
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        if point.x < 0 || point.y < 0 || point.x as usize >= self.width || point.y as usize >= self.height {
+            return None;
+        }
+
+        Some(point.y as usize * self.width + point.x as usize)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index(point).map(|index| &self.cells[index])
+    }
+
+    // returns previous value, or None if point is out of bounds and nothing was set
+    pub fn set(&mut self, point: Point, value: T) -> Option<T> {
+        self.index(point).map(|index| std::mem::replace(&mut self.cells[index], value))
+    }
+
+    // row by row, starting from top left corner
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    #[allow(dead_code)]
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        self.cells.chunks(self.width.max(1)).nth(y).into_iter().flatten()
+    }
+
+    #[allow(dead_code)]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width { &self.cells[x..] } else { &[] };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    // neighbours on top, right, bottom and left that are inside the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.into_iter()
+            .map(move |direction| point + direction)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    // like neighbours4, including diagonals
+    #[allow(dead_code)]
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL.into_iter()
+            .map(|direction| direction.delta())
+            .zip(DIAGONALS)
+            .flat_map(|(straight, diagonal)| [straight, diagonal])
+            .map(move |delta| point + delta)
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, cell) in self.cells.iter().enumerate() {
+            if index > 0 && index % self.width == 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", cell)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 of grid has 2 cells, expected 3")]
+    fn test_parse_ragged() {
+        Grid::parse("abc\nde\n", |c| c);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut grid = example();
+        assert_eq!(grid.set(Point::new(1, 0), 'x'), Some('b'));
+        assert_eq!(grid.set(Point::new(1, 2), 'x'), None);
+        assert_eq!(grid.to_string(), "axc\ndef");
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = example();
+        assert_eq!(grid.row(1).collect::<String>(), "def");
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.cells().map(|(point, c)| format!("{}{}{}", point.x, point.y, c)).collect::<Vec<_>>(),
+            vec!["00a", "10b", "20c", "01d", "11e", "21f"]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(1, 0)).count(), 5);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).map(|point| grid.get(point).unwrap()).collect::<String>(), "bcfda");
+    }
+}
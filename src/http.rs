@@ -0,0 +1,302 @@
+use {
+    std::{
+        fs::{create_dir_all, read_to_string, write},
+        path::PathBuf,
+        sync::{Mutex, OnceLock},
+        thread::sleep,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    },
+    crate::config::{self, Config, ConfigError, REPOSITORY},
+};
+
+const LAST_REQUEST_FILE: &str = "last_request";
+
+static CLIENT: OnceLock<HttpClient> = OnceLock::new();
+
+pub struct HttpOptions {
+    pub timeout: Duration,
+    // between any two requests to advent of code website, including requests made by previous runs
+    pub min_interval: Duration,
+    // file with time of the last request, so that the interval is respected across runs
+    pub state_path: Option<PathBuf>,
+    // retries of GET requests that failed with a connection error, a timeout or 5xx status
+    pub retries: u32,
+    // doubled after each retry
+    pub initial_backoff: Duration,
+}
+
+impl HttpOptions {
+    pub fn for_config(config: &Config) -> Self {
+        Self {
+            timeout: Duration::from_secs(30),
+            min_interval: Duration::from_secs(3),
+            state_path: Some(config.cache_dir.join(LAST_REQUEST_FILE)),
+            retries: 3,
+            initial_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+// client shared by everything that talks to advent of code website
+pub struct HttpClient {
+    config: Config,
+    options: HttpOptions,
+    client: reqwest::blocking::Client,
+    last_request: Mutex<Option<SystemTime>>,
+}
+
+impl HttpClient {
+    pub fn new(config: Config, options: HttpOptions) -> Self {
+        let client = reqwest::blocking::Client::builder()
+            .user_agent(user_agent(&config))
+            .timeout(options.timeout)
+            .build()
+            .expect("failed to create http client");
+
+        Self {
+            config,
+            options,
+            client,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    pub fn get(&self, url: &str, session: Option<&str>) -> Result<HttpResponse, reqwest::Error> {
+        let mut backoff = self.options.initial_backoff;
+        let mut retries_left = self.options.retries;
+
+        loop {
+            let mut request = self.client.get(url);
+            if let Some(session) = session {
+                request = request.header("cookie", format!("session={}", session));
+            }
+
+            let result = self.send(request);
+            let transient = match &result {
+                Ok(response) => response.status >= 500,
+                Err(err) => err.is_timeout() || err.is_connect(),
+            };
+            if !transient || retries_left == 0 {
+                return result;
+            }
+
+            eprintln!("request to {} failed, retrying in {:.1?}", url, backoff);
+            sleep(backoff);
+            backoff *= 2;
+            retries_left -= 1;
+        }
+    }
+
+    // never retried, answer may have been submitted even if response was not received
+    pub fn post_form(&self, url: &str, session: &str, form: &[(&str, String)]) -> Result<HttpResponse, reqwest::Error> {
+        self.send(self.client.post(url)
+            .header("cookie", format!("session={}", session))
+            .form(form))
+    }
+
+    fn send(&self, request: reqwest::blocking::RequestBuilder) -> Result<HttpResponse, reqwest::Error> {
+        self.wait_for_turn();
+
+        let response = request.send()?;
+        Ok(HttpResponse {
+            status: response.status().as_u16(),
+            body: response.text()?,
+        })
+    }
+
+    fn wait_for_turn(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+
+        let persisted = self.options.state_path.as_ref()
+            .and_then(|path| read_to_string(path).ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(previous) = last_request.iter().copied().chain(persisted).max() {
+            let next_allowed = previous + self.options.min_interval;
+            if let Ok(wait) = next_allowed.duration_since(SystemTime::now()) {
+                sleep(wait);
+            }
+        }
+
+        let now = SystemTime::now();
+        *last_request = Some(now);
+
+        if let Some(path) = &self.options.state_path {
+            let millis = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+            let saved = path.parent().map(create_dir_all).unwrap_or(Ok(()))
+                .and_then(|_| write(path, millis.to_string()));
+            if let Err(err) = saved {
+                eprintln!("warning: failed to save time of last request to {}: {}", path.display(), err);
+            }
+        }
+    }
+}
+
+// advent of code asks automated tools to identify themselves with a way to contact the author
+fn user_agent(config: &Config) -> String {
+    user_agent_for(REPOSITORY, config.contact.as_deref())
+}
+
+fn user_agent_for(repository: &str, email: Option<&str>) -> String {
+    let contact = std::iter::once(repository)
+        .chain(email)
+        .filter(|v| !v.is_empty())
+        .collect::<Vec<_>>();
+    format!("{}/{} (contact: {})", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"), contact.join(", "))
+}
+
+pub fn client() -> Result<&'static HttpClient, ConfigError> {
+    if let Some(client) = CLIENT.get() {
+        return Ok(client);
+    }
+
+    let config = config::get().clone();
+    config.check_contact(REPOSITORY)?;
+    Ok(CLIENT.get_or_init(|| {
+        let options = HttpOptions::for_config(&config);
+        HttpClient::new(config, options)
+    }))
+}
+
+#[cfg(test)]
+pub fn test_client(base_url: &str) -> HttpClient {
+    test_client_with(Config {
+        base_url: base_url.to_owned(),
+        contact: Some("test@example.com".to_owned()),
+        ..Config::default()
+    })
+}
+
+// no throttling and no retries
+#[cfg(test)]
+pub fn test_client_with(config: Config) -> HttpClient {
+    HttpClient::new(
+        config,
+        HttpOptions {
+            timeout: Duration::from_secs(5),
+            min_interval: Duration::ZERO,
+            state_path: None,
+            retries: 0,
+            initial_backoff: Duration::ZERO,
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        std::time::Instant,
+        crate::test_server::TestServer,
+        super::*,
+    };
+
+    #[test]
+    fn test_user_agent() {
+        let server = TestServer::start(vec![(200, "ok")]);
+        test_client(&server.base_url).get(&server.base_url, None).unwrap();
+
+        assert_eq!(
+            server.requests()[0].header("user-agent"),
+            Some("advent-of-code-2024/0.1.0 (contact: https://github.com/nikitavbv/advent-of-code-2024, test@example.com)")
+        );
+        assert_eq!(server.requests()[0].header("cookie"), None);
+
+        assert_eq!(
+            user_agent_for("https://example.com/aoc", Some("test@example.com")),
+            "advent-of-code-2024/0.1.0 (contact: https://example.com/aoc, test@example.com)"
+        );
+        assert_eq!(user_agent_for("https://example.com/aoc", None), "advent-of-code-2024/0.1.0 (contact: https://example.com/aoc)");
+    }
+
+    #[test]
+    fn test_retry_server_error() {
+        let server = TestServer::start(vec![(502, "Bad Gateway"), (500, "Internal Server Error"), (200, "1 2 3\n")]);
+        let mut client = test_client(&server.base_url);
+        client.options.retries = 2;
+
+        assert_eq!(
+            client.get(&server.base_url, Some("test-session")).unwrap(),
+            HttpResponse { status: 200, body: "1 2 3\n".to_owned() }
+        );
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn test_retries_exhausted() {
+        let server = TestServer::start(vec![(503, "Unavailable"), (503, "Unavailable")]);
+        let mut client = test_client(&server.base_url);
+        client.options.retries = 1;
+
+        assert_eq!(client.get(&server.base_url, None).unwrap().status, 503);
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_timeout_retried() {
+        // accepts connections into backlog but never responds
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let client = HttpClient::new(Config::default(), HttpOptions {
+            timeout: Duration::from_millis(100),
+            min_interval: Duration::ZERO,
+            state_path: None,
+            retries: 1,
+            initial_backoff: Duration::ZERO,
+        });
+
+        let start = Instant::now();
+        assert!(client.get(&base_url, None).unwrap_err().is_timeout());
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_client_error_not_retried() {
+        let server = TestServer::start(vec![(404, "Not Found"), (200, "unexpected")]);
+        let mut client = test_client(&server.base_url);
+        client.options.retries = 3;
+
+        assert_eq!(client.get(&server.base_url, None).unwrap().status, 404);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_post_not_retried() {
+        let server = TestServer::start(vec![(500, "Internal Server Error"), (200, "unexpected")]);
+        let mut client = test_client(&server.base_url);
+        client.options.retries = 3;
+
+        assert_eq!(client.post_form(&server.base_url, "test-session", &[("level", "1".to_owned())]).unwrap().status, 500);
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_min_interval_persisted_across_clients() {
+        let state_path = std::env::temp_dir().join(format!("aoc-http-{}", std::process::id())).join(LAST_REQUEST_FILE);
+        let server = TestServer::start(vec![(200, "first"), (200, "second")]);
+        let client = |server: &TestServer| {
+            let mut client = test_client(&server.base_url);
+            client.options.min_interval = Duration::from_millis(300);
+            client.options.state_path = Some(state_path.clone());
+            client
+        };
+
+        let start = Instant::now();
+        client(&server).get(&server.base_url, None).unwrap();
+        // a new client, as in the next run of the tool
+        client(&server).get(&server.base_url, None).unwrap();
+        let elapsed = start.elapsed();
+        std::fs::remove_dir_all(state_path.parent().unwrap()).unwrap();
+
+        assert!(elapsed >= Duration::from_millis(300), "requests were {:?} apart", elapsed);
+    }
+}
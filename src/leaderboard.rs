@@ -0,0 +1,337 @@
+use {
+    std::{
+        collections::HashMap,
+        fs::{create_dir_all, read_to_string, write, exists},
+        io,
+        path::Path,
+        time::{Duration, SystemTime},
+    },
+    serde::Deserialize,
+    crate::{
+        config,
+        http::{self, HttpClient},
+        registry::TOTAL_DAYS,
+        session,
+        utils::DownloadError,
+    },
+};
+
+// advent of code asks not to request private leaderboards more often than this
+const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug)]
+pub enum LeaderboardError {
+    NotCached {
+        id: u64,
+    },
+    Download(DownloadError),
+    Request(reqwest::Error),
+    Http {
+        status: u16,
+    },
+    // html page is returned instead of JSON when session is not allowed to view the leaderboard
+    NotALeaderboard,
+    Parse(serde_json::Error),
+    Cache(io::Error),
+}
+
+impl std::fmt::Display for LeaderboardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotCached { id } => write!(f, "leaderboard {} is not cached and offline mode is enabled", id),
+            Self::Download(err) => err.fmt(f),
+            Self::Request(err) => write!(f, "failed to download leaderboard: {}", err),
+            Self::Http { status } => write!(f, "advent of code website responded with status {}", status),
+            Self::NotALeaderboard => write!(f, "response is not a leaderboard, check leaderboard id and that session cookie is still valid"),
+            Self::Parse(err) => write!(f, "failed to parse leaderboard: {}", err),
+            Self::Cache(err) => write!(f, "failed to access leaderboard cache: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for LeaderboardError {}
+
+impl From<DownloadError> for LeaderboardError {
+    fn from(err: DownloadError) -> Self {
+        Self::Download(err)
+    }
+}
+
+impl From<reqwest::Error> for LeaderboardError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Request(err)
+    }
+}
+
+impl From<serde_json::Error> for LeaderboardError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Parse(err)
+    }
+}
+
+impl From<io::Error> for LeaderboardError {
+    fn from(err: io::Error) -> Self {
+        Self::Cache(err)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Leaderboard {
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Member {
+    pub id: u64,
+    // anonymous users have no name
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    // day -> part -> completion
+    #[serde(default)]
+    pub completion_day_level: HashMap<u32, HashMap<u32, Completion>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Completion {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    fn completed_at(&self, day: u32, part: u32) -> Option<u64> {
+        self.completion_day_level.get(&day)?.get(&part).map(|completion| completion.get_star_ts)
+    }
+}
+
+impl Leaderboard {
+    // best local score first, ties broken by name so that output is stable
+    pub fn ranked_members(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|member| (std::cmp::Reverse(member.local_score), member.display_name()));
+        members
+    }
+}
+
+pub fn show_leaderboard(id: u64, day: Option<u32>) -> Result<(), LeaderboardError> {
+    let config = config::get();
+    let cache_path = config.year_cache_dir().join(format!("leaderboard_{}.json", id));
+    // the leaderboard request itself tells if session is still valid, there is no need to validate it separately
+    let connect = || -> Result<_, LeaderboardError> {
+        let (session, _source) = session::load().map_err(DownloadError::Session)?;
+        Ok((http::client().map_err(DownloadError::Config)?, session))
+    };
+    let leaderboard = load_leaderboard(&cache_path, id, config.offline, connect)?;
+
+    match day {
+        Some(day) => print!("{}", render_day(&leaderboard, day)),
+        None => print!("{}", render_overview(&leaderboard)),
+    }
+
+    Ok(())
+}
+
+// downloads leaderboard unless a cached copy is younger than CACHE_TTL. Offline mode uses cached copy of any age.
+// `connect` provides http client and session, it is only called when leaderboard is downloaded.
+fn load_leaderboard<'a>(
+    cache_path: &Path,
+    id: u64,
+    offline: bool,
+    connect: impl FnOnce() -> Result<(&'a HttpClient, String), LeaderboardError>,
+) -> Result<Leaderboard, LeaderboardError> {
+    if let Some(age) = cache_age(cache_path)? {
+        if offline || age < CACHE_TTL {
+            eprintln!("using leaderboard downloaded {} min ago", age.as_secs() / 60);
+            return Ok(serde_json::from_str(&read_to_string(cache_path)?)?);
+        }
+    }
+
+    if offline {
+        return Err(LeaderboardError::NotCached { id });
+    }
+
+    let (client, session) = connect()?;
+    let url = format!("{}/{}/leaderboard/private/view/{}.json", client.config().base_url(), client.config().year, id);
+    let response = client.get(&url, Some(&session))?;
+    if response.status != 200 {
+        return Err(LeaderboardError::Http { status: response.status });
+    }
+    if !response.body.trim_start().starts_with('{') {
+        return Err(LeaderboardError::NotALeaderboard);
+    }
+
+    let leaderboard = serde_json::from_str(&response.body)?;
+    if let Some(parent) = cache_path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(cache_path, &response.body)?;
+
+    Ok(leaderboard)
+}
+
+fn cache_age(path: &Path) -> io::Result<Option<Duration>> {
+    if !exists(path)? {
+        return Ok(None);
+    }
+
+    let modified = std::fs::metadata(path)?.modified()?;
+    Ok(Some(SystemTime::now().duration_since(modified).unwrap_or_default()))
+}
+
+// one row per member, '*' for both stars of a day, '+' for first star only
+fn render_overview(leaderboard: &Leaderboard) -> String {
+    let days = (1..=TOTAL_DAYS).map(|day| (day % 10).to_string()).collect::<String>();
+    let mut output = format!("{:>4} {:>6} {:>5}  {}  {}\n", "", "score", "stars", days, "name");
+
+    for (rank, member) in leaderboard.ranked_members().into_iter().enumerate() {
+        let stars = (1..=TOTAL_DAYS)
+            .map(|day| match (member.completed_at(day, 1), member.completed_at(day, 2)) {
+                (Some(_), Some(_)) => '*',
+                (Some(_), None) => '+',
+                _ => '.',
+            })
+            .collect::<String>();
+        output += &format!(
+            "{:>4} {:>6} {:>5}  {}  {}\n",
+            format!("{})", rank + 1),
+            member.local_score,
+            member.stars,
+            stars,
+            member.display_name()
+        );
+    }
+
+    output
+}
+
+// completion times of both parts of a day, in order of completion of the second part
+fn render_day(leaderboard: &Leaderboard, day: u32) -> String {
+    let mut members = leaderboard.ranked_members();
+    members.retain(|member| member.completed_at(day, 1).is_some());
+    members.sort_by_key(|member| (member.completed_at(day, 2).unwrap_or(u64::MAX), member.completed_at(day, 1)));
+
+    let mut output = format!("day {}\n{:>19}  {:>19}  {}\n", day, "part 1", "part 2", "name");
+    for member in members {
+        let time = |part| member.completed_at(day, part).map(format_timestamp).unwrap_or_else(|| "-".to_owned());
+        output += &format!("{:>19}  {:>19}  {}\n", time(1), time(2), member.display_name());
+    }
+
+    output
+}
+
+// unix timestamp as UTC date and time
+fn format_timestamp(timestamp: u64) -> String {
+    let (days, seconds) = (timestamp / 86400, timestamp % 86400);
+
+    // civil date from days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        std::path::PathBuf,
+        crate::{http::test_client, test_server::TestServer},
+        super::*,
+    };
+
+    const LEADERBOARD: &str = r#"{"event":"2024","owner_id":1,"members":{
+        "1":{"id":1,"name":"Alice","stars":3,"local_score":10,"global_score":0,"last_star_ts":1733463153,
+            "completion_day_level":{"1":{"1":{"get_star_ts":1733029200,"star_index":1},"2":{"get_star_ts":1733029500,"star_index":2}},
+                                    "2":{"1":{"get_star_ts":1733116000,"star_index":3}}}},
+        "2":{"id":2,"name":null,"stars":2,"local_score":6,"global_score":0,"last_star_ts":1733030000,
+            "completion_day_level":{"1":{"1":{"get_star_ts":1733029300,"star_index":4},"2":{"get_star_ts":1733030000,"star_index":5}}}},
+        "3":{"id":3,"name":"Bob","stars":0,"local_score":0,"global_score":0,"last_star_ts":0,"completion_day_level":{}}
+    }}"#;
+
+    fn temp_cache_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-leaderboard-{}-{}", name, std::process::id())).join("leaderboard.json")
+    }
+
+    #[test]
+    fn test_render_overview() {
+        let leaderboard = serde_json::from_str(LEADERBOARD).unwrap();
+        assert_eq!(
+            render_overview(&leaderboard),
+            "      score stars  1234567890123456789012345  name\n  \
+            1)     10     3  *+.......................  Alice\n  \
+            2)      6     2  *........................  (anonymous user #2)\n  \
+            3)      0     0  .........................  Bob\n"
+        );
+    }
+
+    #[test]
+    fn test_render_day() {
+        let leaderboard = serde_json::from_str(LEADERBOARD).unwrap();
+        assert_eq!(
+            render_day(&leaderboard, 1),
+            "day 1\n             part 1               part 2  name\n\
+             2024-12-01 05:00:00  2024-12-01 05:05:00  Alice\n\
+            2024-12-01 05:01:40  2024-12-01 05:13:20  (anonymous user #2)\n"
+        );
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+        assert_eq!(format_timestamp(1709208000), "2024-02-29 12:00:00");
+    }
+
+    #[test]
+    fn test_load_leaderboard_uses_fresh_cache() {
+        let server = TestServer::start(vec![(200, LEADERBOARD)]);
+        let client = test_client(&server.base_url);
+        let cache_path = temp_cache_path("fresh");
+
+        let downloaded = load_leaderboard(&cache_path, 1234, false, || Ok((&client, "test-session".to_owned()))).unwrap();
+        let cached = load_leaderboard(&cache_path, 1234, false, || panic!("client is not needed for cached leaderboard"));
+        std::fs::remove_dir_all(cache_path.parent().unwrap()).unwrap();
+
+        assert_eq!(downloaded.members.len(), 3);
+        assert_eq!(cached.unwrap().members.len(), 3);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/2024/leaderboard/private/view/1234.json");
+        assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    }
+
+    #[test]
+    fn test_load_leaderboard_offline() {
+        assert!(matches!(
+            load_leaderboard(&temp_cache_path("offline"), 1234, true, || panic!("offline mode must not build a client")),
+            Err(LeaderboardError::NotCached { id: 1234 })
+        ));
+    }
+
+    #[test]
+    fn test_load_leaderboard_not_a_member() {
+        let server = TestServer::start(vec![(200, "<!DOCTYPE html><html><body>Private Leaderboard</body></html>")]);
+        let cache_path = temp_cache_path("html");
+
+        let client = test_client(&server.base_url);
+        let result = load_leaderboard(&cache_path, 1234, false, || Ok((&client, "test-session".to_owned())));
+        assert!(matches!(result, Err(LeaderboardError::NotALeaderboard)));
+        assert!(!cache_path.exists());
+    }
+}
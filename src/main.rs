@@ -1,3 +1,16 @@
+use {
+    std::{error::Error, path::Path, process::ExitCode},
+    clap::Parser,
+    crate::{
+        bench::BenchOptions,
+        answers::AnswerStore,
+        cli::{AnswersCommand, CacheCommand, Cli, Command, SessionCommand},
+        config::{Config, ConfigOverrides},
+        progress::ProgressMode,
+        runner::OutputFormat,
+    },
+};
+
 mod day01;
 mod day02;
 mod day03;
@@ -11,8 +24,183 @@ mod day10;
 mod day11;
 mod day12;
 
+mod answers;
+mod bench;
+mod cli;
+mod config;
+mod examples;
+mod grid;
+mod http;
+mod leaderboard;
+mod memo;
+mod parse;
+mod point;
+mod progress;
+mod registry;
+mod regression;
+mod runner;
+mod scaffold;
+mod search;
+mod session;
+mod solution;
+mod submit;
 mod utils;
 
-fn main() {
-    day12::part2::run();
+#[cfg(test)]
+mod test_server;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let config = Config::load(&ConfigOverrides {
+        config_path: cli.config,
+        year: cli.year,
+        base_url: cli.base_url,
+        cache_dir: cli.cache_dir,
+        offline: cli.offline,
+    });
+    match config {
+        Ok(config) => config::init(config),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    match execute(cli.command) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Run { day, part, all, input, jobs, format, progress } => {
+            let progress = progress.unwrap_or(match format {
+                OutputFormat::Text => ProgressMode::Bar,
+                OutputFormat::Json => ProgressMode::Json,
+            });
+            if all {
+                runner::run_all(jobs.unwrap_or(1), format, progress)?;
+            } else {
+                runner::run(day.expect("day is required unless --all is set"), part, input.as_deref(), format, progress)?;
+            }
+        },
+        Command::Submit { day, part, answer, force } => {
+            submit::submit(day, part, answer, force)?;
+        },
+        Command::Answers { command: AnswersCommand::Show { day } } => show_answers(day)?,
+        Command::Answers { command: AnswersCommand::Record { day, part, answer, verdict } } => {
+            AnswerStore::load_default()?.record(day, part, &answer, verdict)?;
+        },
+        Command::Verify => regression::verify_all()?,
+        Command::Cache { command: CacheCommand::Audit { evict } } => audit_cache(evict)?,
+        Command::Examples { day, force } => {
+            for path in examples::fetch_examples(day, force)? {
+                println!("saved {}", path.display());
+            }
+        },
+        Command::Leaderboard { id, day } => leaderboard::show_leaderboard(id, day)?,
+        Command::Session { command: SessionCommand::Set { cookie } } => set_session(cookie)?,
+        Command::Session { command: SessionCommand::Check } => check_session()?,
+        Command::New { day } => {
+            for path in scaffold::scaffold(Path::new(scaffold::SOURCE_DIR), day)? {
+                println!("wrote {}", path.display());
+            }
+            println!("save examples for tests with `aoc examples {}`", day);
+        },
+        Command::Bench { day, part, iterations, warmup, baseline, save_baseline, threshold } => bench::bench(day, part, &BenchOptions {
+            iterations,
+            warmup,
+            baseline,
+            save_baseline,
+            threshold,
+        })?,
+    };
+
+    Ok(())
+}
+
+fn show_answers(day: Option<u32>) -> Result<(), Box<dyn Error>> {
+    let answers = AnswerStore::load_default()?;
+
+    let mut parts = answers.records().iter()
+        .filter(|record| day.is_none() || day == Some(record.day))
+        .map(|record| (record.day, record.part))
+        .collect::<Vec<_>>();
+    parts.dedup();
+
+    for (day, part) in parts {
+        println!("day {} part {}:", day, part);
+        for record in answers.records().iter().filter(|record| record.day == day && record.part == part) {
+            println!("  {} {}", record.verdict, record.answer);
+        }
+        match answers.bounds(day, part) {
+            (None, None) => {},
+            (lower, upper) => println!(
+                "  answer is between {} and {}",
+                lower.map(|v| v.to_string()).unwrap_or("-inf".to_owned()),
+                upper.map(|v| v.to_string()).unwrap_or("+inf".to_owned()),
+            ),
+        }
+    }
+
+    Ok(())
+}
+
+fn audit_cache(evict: bool) -> Result<(), Box<dyn Error>> {
+    let entries = utils::audit_cache(evict)?;
+    if entries.is_empty() {
+        println!("no cached inputs");
+    }
+
+    for entry in &entries {
+        match (&entry.problem, entry.evicted) {
+            (None, _) => println!("day {}: ok", entry.day),
+            (Some(problem), false) => println!("day {}: invalid, {}", entry.day, problem),
+            (Some(problem), true) => println!("day {}: invalid, {} (evicted)", entry.day, problem),
+        }
+    }
+
+    let invalid = entries.iter().filter(|entry| entry.problem.is_some() && !entry.evicted).count();
+    if invalid > 0 {
+        return Err(format!("{} cached input(s) are invalid, run with --evict to remove them", invalid).into());
+    }
+
+    Ok(())
+}
+
+fn set_session(cookie: Option<String>) -> Result<(), Box<dyn Error>> {
+    let cookie = match cookie {
+        Some(v) => v,
+        None => {
+            let mut line = String::new();
+            std::io::stdin().read_line(&mut line)?;
+            line
+        },
+    };
+    let cookie = cookie.trim().trim_start_matches("session=");
+    if cookie.is_empty() {
+        return Err("session cookie is empty".into());
+    }
+
+    let path = session::save(cookie)?;
+    println!("saved session cookie to {}", path.display());
+
+    Ok(())
+}
+
+fn check_session() -> Result<(), Box<dyn Error>> {
+    let (session, source) = session::load()?;
+    println!("using session cookie from {}", source);
+
+    if config::get().offline {
+        return Err("session cookie can not be checked in offline mode".into());
+    }
+    session::validate(http::client()?, &session)?;
+    println!("session cookie is valid");
+
+    Ok(())
 }
@@ -0,0 +1,97 @@
+use std::{collections::HashMap, fmt, hash::Hash};
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} hits, {} misses, {} entries", self.hits, self.misses, self.entries)
+    }
+}
+
+// cache of results of a recursive function, owned by the caller instead of the solver's input
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    // once reached, new results are still computed but no longer cached
+    max_entries: Option<usize>,
+    stats: MemoStats,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            max_entries: None,
+            stats: MemoStats::default(),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn bounded(max_entries: usize) -> Self {
+        Self {
+            max_entries: Some(max_entries),
+            ..Self::new()
+        }
+    }
+
+    // calls f with a function to use for recursive calls instead of calling itself, so that every
+    // subproblem goes through the cache:
+    //   memo.solve(n, &|recurse, n| if *n < 2 { *n } else { recurse(n - 1) + recurse(n - 2) })
+    pub fn solve(&mut self, key: K, f: &impl Fn(&mut dyn FnMut(K) -> V, &K) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+
+        let value = f(&mut |key| self.solve(key, f), &key);
+        if self.max_entries.is_none_or(|max_entries| self.cache.len() < max_entries) {
+            self.cache.insert(key, value.clone());
+        }
+
+        value
+    }
+
+    #[allow(dead_code)]
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            entries: self.cache.len(),
+            ..self.stats
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(recurse: &mut dyn FnMut(u64) -> u64, n: &u64) -> u64 {
+        if *n < 2 {
+            *n
+        } else {
+            recurse(n - 1) + recurse(n - 2)
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut memo = Memo::new();
+        assert_eq!(memo.solve(90, &fibonacci), 2880067194370816120);
+        assert_eq!(memo.stats(), MemoStats { hits: 88, misses: 91, entries: 91 });
+
+        assert_eq!(memo.solve(50, &fibonacci), 12586269025);
+        assert_eq!(memo.stats().hits, 89);
+    }
+
+    #[test]
+    fn test_bounded() {
+        let mut memo = Memo::bounded(10);
+        assert_eq!(memo.solve(30, &fibonacci), 832040);
+        assert_eq!(memo.stats().entries, 10);
+        assert_eq!(MemoStats { hits: 1, misses: 2, entries: 3 }.to_string(), "1 hits, 2 misses, 3 entries");
+    }
+}
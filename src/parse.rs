@@ -0,0 +1,296 @@
+use {
+    std::{fmt::Display, str::FromStr},
+    crate::grid::Grid,
+};
+
+// line and column are counted from 1, as shown by editors
+#[derive(Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+// non-empty lines of input with their line numbers
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines()
+        .enumerate()
+        .map(|(index, text)| Line { number: index + 1, text })
+        .filter(|line| !line.is_blank())
+}
+
+// groups of lines separated by one or more blank lines
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = Vec::new();
+    let mut current = Vec::new();
+
+    for (index, text) in input.lines().enumerate() {
+        let line = Line { number: index + 1, text };
+        if !line.is_blank() {
+            current.push(line);
+        } else if !current.is_empty() {
+            sections.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+
+    sections
+}
+
+impl<'a> Line<'a> {
+    fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    // error pointing to a byte offset in this line
+    pub fn error_at(&self, offset: usize, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.text[..offset].chars().count() + 1, message)
+    }
+
+    // all integers in the line, ignoring anything between them. A '-' right before digits makes the number negative.
+    // lines in a known format should use separated instead, so that unexpected text is reported.
+    pub fn integers<T: FromStr>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut result = Vec::new();
+
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let negative = bytes[offset] == b'-' && bytes.get(offset + 1).is_some_and(u8::is_ascii_digit);
+            if !negative && !bytes[offset].is_ascii_digit() {
+                offset += 1;
+                continue;
+            }
+
+            let start = offset;
+            offset += 1;
+            while offset < bytes.len() && bytes[offset].is_ascii_digit() {
+                offset += 1;
+            }
+            result.push(self.parse_at(start, &self.text[start..offset], "number")?);
+        }
+
+        Ok(result)
+    }
+
+    // values separated by `separator`, every one of them must parse. Whitespace around values is ignored and
+    // a whitespace separator matches any amount of whitespace.
+    pub fn separated<T: FromStr>(&self, separator: char) -> Result<Vec<T>, ParseError>
+    where
+        T::Err: Display,
+    {
+        if separator.is_whitespace() {
+            return tokens(self.text).map(|(offset, token)| self.parse_at(offset, token, "value")).collect();
+        }
+
+        let mut start = 0;
+        self.text.split(separator)
+            .map(|part| {
+                let offset = start + part.len() - part.trim_start().len();
+                start += part.len() + separator.len_utf8();
+                if part.trim().is_empty() {
+                    return Err(self.error_at(offset, format!("missing value, expected values separated by {:?}", separator)));
+                }
+                self.parse_at(offset, part.trim(), "value")
+            })
+            .collect()
+    }
+
+    // like separated, for lines with a fixed number of values
+    pub fn separated_array<T: FromStr, const N: usize>(&self, separator: char) -> Result<[T; N], ParseError>
+    where
+        T::Err: Display,
+    {
+        let values = self.separated(separator)?;
+        let found = values.len();
+        values.try_into().map_err(|_| self.error_at(0, format!("expected {} values, found {}", N, found)))
+    }
+
+    // like integers, for lines with a fixed number of them
+    pub fn integer_array<T: FromStr, const N: usize>(&self) -> Result<[T; N], ParseError>
+    where
+        T::Err: Display,
+    {
+        let integers = self.integers()?;
+        let found = integers.len();
+        integers.try_into().map_err(|_| self.error_at(0, format!("expected {} numbers, found {}", N, found)))
+    }
+
+    // "key: value value ...", values are separated by whitespace
+    pub fn key_values<K: FromStr, V: FromStr>(&self) -> Result<(K, Vec<V>), ParseError>
+    where
+        K::Err: Display,
+        V::Err: Display,
+    {
+        let (key, values) = self.text.split_once(':')
+            .ok_or_else(|| self.error_at(self.text.len(), "expected \"key: values\""))?;
+
+        if key.trim().is_empty() {
+            return Err(self.error_at(0, "missing key before ':'"));
+        }
+        let key = self.parse_at(key.len() - key.trim_start().len(), key.trim(), "key")?;
+
+        let values_start = self.text.len() - values.len();
+        let values = tokens(values)
+            .map(|(offset, token)| self.parse_at(values_start + offset, token, "value"))
+            .collect::<Result<_, _>>()?;
+
+        Ok((key, values))
+    }
+
+    fn parse_at<T: FromStr>(&self, offset: usize, text: &str, what: &str) -> Result<T, ParseError>
+    where
+        T::Err: Display,
+    {
+        text.parse().map_err(|err| self.error_at(offset, format!("invalid {} \"{}\": {}", what, text, err)))
+    }
+}
+
+// whitespace separated tokens with their byte offsets
+fn tokens(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_inclusive(char::is_whitespace)
+        .scan(0, |offset, part| {
+            let start = *offset;
+            *offset += part.len();
+            Some((start, part.trim_end()))
+        })
+        .filter(|(_, token)| !token.is_empty())
+}
+
+// one cell per char, every char must be in the legend
+pub fn grid_with_legend<T: Clone>(input: &str, legend: &[(char, T)]) -> Result<Grid<T>, ParseError> {
+    let mut rows: Vec<Vec<T>> = Vec::new();
+
+    for line in lines(input) {
+        let row = line.text
+            .char_indices()
+            .map(|(offset, c)| legend.iter()
+                .find(|(symbol, _)| *symbol == c)
+                .map(|(_, cell)| cell.clone())
+                .ok_or_else(|| line.error_at(offset, format!(
+                    "unexpected {:?}, expected one of: {}",
+                    c,
+                    legend.iter().map(|(symbol, _)| format!("{:?}", symbol)).collect::<Vec<_>>().join(", ")
+                ))))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let width = rows.first().map(|first| first.len()).unwrap_or(row.len());
+        if row.len() != width {
+            return Err(line.error_at(0, format!("expected {} cells like on previous lines, found {}", width, row.len())));
+        }
+        rows.push(row);
+    }
+
+    Ok(Grid::from_rows(rows))
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::point::Point,
+        super::*,
+    };
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 3, text }
+    }
+
+    #[test]
+    fn test_lines_and_sections() {
+        let input = "a\n\nb\nc\n \n\nd\n";
+        assert_eq!(lines(input).map(|line| (line.number, line.text)).collect::<Vec<_>>(), vec![(1, "a"), (3, "b"), (4, "c"), (7, "d")]);
+        assert_eq!(
+            sections(input).iter().map(|section| section.iter().map(|line| line.number).collect::<Vec<_>>()).collect::<Vec<_>>(),
+            vec![vec![1], vec![3, 4], vec![7]]
+        );
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(line("x=-12, y=7 (3-4)").integers::<i32>(), Ok(vec![-12, 7, 3, -4]));
+        assert_eq!(line("3   4").integer_array::<u64, 2>(), Ok([3, 4]));
+        assert_eq!(
+            line("1 2 3").integer_array::<u64, 2>(),
+            Err(ParseError::new(3, 1, "expected 2 numbers, found 3"))
+        );
+        assert_eq!(
+            line("12 -4").integers::<u32>().unwrap_err().to_string(),
+            "line 3, column 4: invalid number \"-4\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_separated() {
+        assert_eq!(line("75,47, 61").separated::<u32>(','), Ok(vec![75, 47, 61]));
+        assert_eq!(line(" 7 6  4 ").separated::<u32>(' '), Ok(vec![7, 6, 4]));
+        assert_eq!(line("47|53").separated_array::<u32, 2>('|'), Ok([47, 53]));
+        assert_eq!(
+            line("7 6x 4").separated::<u32>(' ').unwrap_err().to_string(),
+            "line 3, column 3: invalid value \"6x\": invalid digit found in string"
+        );
+        assert_eq!(
+            line("75,,61").separated::<u32>(','),
+            Err(ParseError::new(3, 4, "missing value, expected values separated by ','"))
+        );
+        assert_eq!(
+            line("47|53|61").separated_array::<u32, 2>('|'),
+            Err(ParseError::new(3, 1, "expected 2 values, found 3"))
+        );
+    }
+
+    #[test]
+    fn test_key_values() {
+        assert_eq!(line("3267: 81 40 27").key_values::<u64, u64>(), Ok((3267, vec![81, 40, 27])));
+        assert_eq!(line("a:b c").key_values::<String, String>(), Ok(("a".to_owned(), vec!["b".to_owned(), "c".to_owned()])));
+        assert_eq!(line("190 10 19").key_values::<u64, u64>(), Err(ParseError::new(3, 10, "expected \"key: values\"")));
+        assert_eq!(
+            line("190: 10 x9").key_values::<u64, u64>().unwrap_err(),
+            ParseError::new(3, 9, "invalid value \"x9\": invalid digit found in string")
+        );
+        assert_eq!(line(": 10").key_values::<u64, u64>(), Err(ParseError::new(3, 1, "missing key before ':'")));
+    }
+
+    #[test]
+    fn test_grid_with_legend() {
+        let legend = [('.', false), ('#', true)];
+        let grid = grid_with_legend(".#\n#.\n", &legend).unwrap();
+        assert_eq!(grid.get(Point::new(1, 0)), Some(&true));
+
+        assert_eq!(
+            grid_with_legend(".#\n.x\n", &legend).unwrap_err().to_string(),
+            "line 2, column 2: unexpected 'x', expected one of: '.', '#'"
+        );
+        assert_eq!(
+            grid_with_legend(".#\n.\n", &legend),
+            Err(ParseError::new(2, 1, "expected 2 cells like on previous lines, found 1"))
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+// (0, 0) is top left corner, x grows to the right and y grows down.
+// used both as a position and as a vector between two positions. Signed, so that positions
+// next to a grid can be represented and are simply out of bounds.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self {
+            x,
+            y,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn manhattan_distance(&self, other: &Point) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    // by 90 degrees clockwise as seen on screen, around (0, 0)
+    #[allow(dead_code)]
+    pub fn rotate_right(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    #[allow(dead_code)]
+    pub fn rotate_left(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Self;
+
+    fn mul(self, scale: i32) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl Direction {
+    // clockwise, starting from top
+    pub const ALL: [Self; 4] = [Self::Top, Self::Right, Self::Bottom, Self::Left];
+
+    pub fn delta(&self) -> Point {
+        match self {
+            Self::Top => Point::new(0, -1),
+            Self::Right => Point::new(1, 0),
+            Self::Bottom => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Self::Top => Self::Right,
+            Self::Right => Self::Bottom,
+            Self::Bottom => Self::Left,
+            Self::Left => Self::Top,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn perpendicular(&self) -> [Self; 2] {
+        [self.turn_right(), self.turn_left()]
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+
+        assert_eq!(a + b, Point::new(2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a.manhattan_distance(&b), 10);
+    }
+
+    #[test]
+    fn test_rotation() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.delta().rotate_right(), direction.turn_right().delta());
+            assert_eq!(direction.delta().rotate_left(), direction.turn_left().delta());
+            assert_eq!(-direction.delta(), direction.opposite().delta());
+        }
+        assert_eq!(Point::new(2, 1).rotate_right().rotate_left(), Point::new(2, 1));
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::Top.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.perpendicular(), [Direction::Top, Direction::Bottom]);
+        assert_eq!(Point::new(1, 1) + Direction::Bottom, Point::new(1, 2));
+    }
+}
@@ -0,0 +1,256 @@
+use {
+    std::{io::{self, Write}, str::FromStr, sync::{atomic::{AtomicU64, Ordering}, Mutex, OnceLock}},
+    indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle},
+    serde::Serialize,
+};
+
+// implemented by reporters that long-running solvers can tell about their progress.
+pub trait Progress: Sync {
+    fn start(&self, total: u64);
+    fn advance(&self, delta: u64);
+    fn finish(&self);
+}
+
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn start(&self, _total: u64) {}
+    fn advance(&self, _delta: u64) {}
+    fn finish(&self) {}
+}
+
+// bar is only drawn once the part starts reporting progress, most parts never do
+pub struct BarProgress {
+    bars: MultiProgress,
+    prefix: String,
+    bar: OnceLock<ProgressBar>,
+}
+
+impl Progress for BarProgress {
+    fn start(&self, total: u64) {
+        let bar = self.bar.get_or_init(|| {
+            let bar = self.bars.add(ProgressBar::no_length());
+            bar.set_style(ProgressStyle::with_template("day {prefix} {wide_bar} {pos}/{len} {eta}").unwrap());
+            bar.set_prefix(self.prefix.clone());
+            bar
+        });
+        bar.set_length(total);
+        bar.reset();
+    }
+
+    fn advance(&self, delta: u64) {
+        if let Some(bar) = self.bar.get() {
+            bar.inc(delta);
+        }
+    }
+
+    fn finish(&self) {
+        if let Some(bar) = self.bar.get() {
+            bar.finish_and_clear();
+            self.bars.remove(bar);
+        }
+    }
+}
+
+// one JSON object per line whenever another percent is done:
+// {"event":"progress","day":6,"part":2,"done":2520,"total":5040}
+pub struct JsonProgress<W: Write + Send> {
+    day: u32,
+    part: u32,
+    total: AtomicU64,
+    done: AtomicU64,
+    output: Mutex<W>,
+}
+
+#[derive(Serialize)]
+struct ProgressTick {
+    event: &'static str,
+    day: u32,
+    part: u32,
+    done: u64,
+    total: u64,
+}
+
+impl<W: Write + Send> JsonProgress<W> {
+    pub fn new(day: u32, part: u32, output: W) -> Self {
+        Self {
+            day,
+            part,
+            total: AtomicU64::new(0),
+            done: AtomicU64::new(0),
+            output: Mutex::new(output),
+        }
+    }
+
+    fn report(&self, done: u64, total: u64) {
+        let tick = ProgressTick {
+            event: "progress",
+            day: self.day,
+            part: self.part,
+            done,
+            total,
+        };
+        let line = serde_json::to_string(&tick).expect("progress tick is always serializable");
+        // progress is not worth failing the solution for
+        let _ = writeln!(self.output.lock().unwrap(), "{}", line);
+    }
+}
+
+impl<W: Write + Send> Progress for JsonProgress<W> {
+    fn start(&self, total: u64) {
+        self.total.store(total, Ordering::Relaxed);
+        self.done.store(0, Ordering::Relaxed);
+        self.report(0, total);
+    }
+
+    fn advance(&self, delta: u64) {
+        let total = self.total.load(Ordering::Relaxed);
+        let done = self.done.fetch_add(delta, Ordering::Relaxed) + delta;
+        if total > 0 && (done - delta) * 100 / total != done * 100 / total {
+            self.report(done, total);
+        }
+    }
+
+    fn finish(&self) {
+        let total = self.total.load(Ordering::Relaxed);
+        if self.done.load(Ordering::Relaxed) < total {
+            self.report(total, total);
+        }
+    }
+}
+
+// reports progress of each item when iterated over.
+pub fn track<'a, I: ExactSizeIterator + 'a>(iter: I, progress: &'a dyn Progress) -> impl Iterator<Item = I::Item> + 'a {
+    progress.start(iter.len() as u64);
+    iter.inspect(move |_| progress.advance(1))
+        .chain(std::iter::from_fn(move || {
+            progress.finish();
+            None
+        }))
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ProgressMode {
+    Bar,
+    None,
+    Json,
+}
+
+impl FromStr for ProgressMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "bar" => Self::Bar,
+            "none" => Self::None,
+            "json" => Self::Json,
+            other => return Err(format!("unknown progress mode \"{}\", expected one of: bar, none, json", other)),
+        })
+    }
+}
+
+// creates progress reporters for parts, bars of parts running in parallel are drawn together.
+pub struct ProgressReporter {
+    mode: ProgressMode,
+    bars: MultiProgress,
+}
+
+impl ProgressReporter {
+    pub fn new(mode: ProgressMode) -> Self {
+        Self {
+            mode,
+            bars: MultiProgress::with_draw_target(ProgressDrawTarget::stderr()),
+        }
+    }
+
+    pub fn for_part(&self, day: u32, part: u32) -> Box<dyn Progress> {
+        match self.mode {
+            ProgressMode::Bar => Box::new(BarProgress {
+                bars: self.bars.clone(),
+                prefix: format!("{} part {}", day, part),
+                bar: OnceLock::new(),
+            }),
+            ProgressMode::None => Box::new(NoProgress),
+            ProgressMode::Json => Box::new(JsonProgress::new(day, part, io::stderr())),
+        }
+    }
+
+    // hides bars while `f` prints, so that output is not mixed with them
+    pub fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.bars.suspend(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingProgress {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl Progress for RecordingProgress {
+        fn start(&self, total: u64) {
+            self.events.lock().unwrap().push(format!("start {}", total));
+        }
+
+        fn advance(&self, delta: u64) {
+            self.events.lock().unwrap().push(format!("advance {}", delta));
+        }
+
+        fn finish(&self) {
+            self.events.lock().unwrap().push("finish".to_owned());
+        }
+    }
+
+    #[test]
+    fn test_track() {
+        let progress = RecordingProgress::default();
+        assert_eq!(track([1, 2].iter(), &progress).sum::<i32>(), 3);
+        assert_eq!(
+            *progress.events.lock().unwrap(),
+            vec!["start 2", "advance 1", "advance 1", "finish"]
+        );
+    }
+
+    #[test]
+    fn test_json_progress() {
+        let progress = JsonProgress::new(6, 2, Vec::new());
+        progress.start(200);
+        for _ in 0..5 {
+            progress.advance(1);
+        }
+        progress.finish();
+
+        assert_eq!(
+            String::from_utf8(progress.output.into_inner().unwrap()).unwrap(),
+            "{\"event\":\"progress\",\"day\":6,\"part\":2,\"done\":0,\"total\":200}\n\
+            {\"event\":\"progress\",\"day\":6,\"part\":2,\"done\":2,\"total\":200}\n\
+            {\"event\":\"progress\",\"day\":6,\"part\":2,\"done\":4,\"total\":200}\n\
+            {\"event\":\"progress\",\"day\":6,\"part\":2,\"done\":200,\"total\":200}\n"
+        );
+    }
+
+    #[test]
+    fn test_bar_progress_is_lazy() {
+        let progress = BarProgress {
+            bars: MultiProgress::with_draw_target(ProgressDrawTarget::hidden()),
+            prefix: "6 part 2".to_owned(),
+            bar: OnceLock::new(),
+        };
+        progress.advance(1);
+        assert!(progress.bar.get().is_none());
+
+        progress.start(10);
+        progress.advance(3);
+        assert_eq!(progress.bar.get().unwrap().position(), 3);
+        progress.finish();
+    }
+
+    #[test]
+    fn test_progress_mode() {
+        assert_eq!("json".parse(), Ok(ProgressMode::Json));
+        assert!("spinner".parse::<ProgressMode>().is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    day01::Day01,
+    day02::Day02,
+    day03::Day03,
+    day04::Day04,
+    day05::Day05,
+    day06::Day06,
+    day07::Day07,
+    day08::Day08,
+    day09::Day09,
+    day10::Day10,
+    day11::Day11,
+    day12::Day12,
+    progress::Progress,
+    solution::{PartResult, solve_part1, solve_part2},
+};
+
+pub const TOTAL_DAYS: u32 = 25;
+
+pub struct PuzzlePart {
+    pub day: u32,
+    pub part: u32,
+    pub solve: fn(&str, &dyn Progress) -> PartResult,
+}
+
+impl PuzzlePart {
+    pub const fn new(day: u32, part: u32, solve: fn(&str, &dyn Progress) -> PartResult) -> Self {
+        Self {
+            day,
+            part,
+            solve,
+        }
+    }
+}
+
+pub const PARTS: &[PuzzlePart] = &[
+    PuzzlePart::new(1, 1, solve_part1::<Day01>),
+    PuzzlePart::new(1, 2, solve_part2::<Day01>),
+    PuzzlePart::new(2, 1, solve_part1::<Day02>),
+    PuzzlePart::new(2, 2, solve_part2::<Day02>),
+    PuzzlePart::new(3, 1, solve_part1::<Day03>),
+    PuzzlePart::new(3, 2, solve_part2::<Day03>),
+    PuzzlePart::new(4, 1, solve_part1::<Day04>),
+    PuzzlePart::new(4, 2, solve_part2::<Day04>),
+    PuzzlePart::new(5, 1, solve_part1::<Day05>),
+    PuzzlePart::new(5, 2, solve_part2::<Day05>),
+    PuzzlePart::new(6, 1, solve_part1::<Day06>),
+    PuzzlePart::new(6, 2, solve_part2::<Day06>),
+    PuzzlePart::new(7, 1, solve_part1::<Day07>),
+    PuzzlePart::new(7, 2, solve_part2::<Day07>),
+    PuzzlePart::new(8, 1, solve_part1::<Day08>),
+    PuzzlePart::new(8, 2, solve_part2::<Day08>),
+    PuzzlePart::new(9, 1, solve_part1::<Day09>),
+    PuzzlePart::new(9, 2, solve_part2::<Day09>),
+    PuzzlePart::new(10, 1, solve_part1::<Day10>),
+    PuzzlePart::new(10, 2, solve_part2::<Day10>),
+    PuzzlePart::new(11, 1, solve_part1::<Day11>),
+    PuzzlePart::new(11, 2, solve_part2::<Day11>),
+    PuzzlePart::new(12, 1, solve_part1::<Day12>),
+    PuzzlePart::new(12, 2, solve_part2::<Day12>),
+];
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum RegistryError {
+    UnknownDay(u32),
+    UnknownPart(u32),
+    DayNotImplemented(u32),
+    PartNotImplemented {
+        day: u32,
+        part: u32,
+    },
+}
+
+impl std::fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownDay(day) => write!(f, "day {} does not exist, advent of code has days 1 to {}", day, TOTAL_DAYS),
+            Self::UnknownPart(part) => write!(f, "part {} does not exist, every day has parts 1 and 2", part),
+            Self::DayNotImplemented(day) => write!(f, "day {} is not implemented yet", day),
+            Self::PartNotImplemented { day, part } => write!(f, "part {} of day {} is not implemented yet", part, day),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+pub fn parts_for_day(day: u32) -> Result<Vec<&'static PuzzlePart>, RegistryError> {
+    if day == 0 || day > TOTAL_DAYS {
+        return Err(RegistryError::UnknownDay(day));
+    }
+
+    let parts = PARTS.iter().filter(|part| part.day == day).collect::<Vec<_>>();
+    if parts.is_empty() {
+        return Err(RegistryError::DayNotImplemented(day));
+    }
+
+    Ok(parts)
+}
+
+pub fn find_part(day: u32, part: u32) -> Result<&'static PuzzlePart, RegistryError> {
+    if part != 1 && part != 2 {
+        return Err(RegistryError::UnknownPart(part));
+    }
+
+    parts_for_day(day)?
+        .into_iter()
+        .find(|puzzle_part| puzzle_part.part == part)
+        .ok_or(RegistryError::PartNotImplemented { day, part })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_part() {
+        let part = find_part(6, 2).unwrap();
+        assert_eq!((part.day, part.part), (6, 2));
+    }
+
+    #[test]
+    fn test_parts_for_day() {
+        assert_eq!(parts_for_day(3).unwrap().iter().map(|part| part.part).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_unknown_day() {
+        assert_eq!(find_part(26, 1).err(), Some(RegistryError::UnknownDay(26)));
+        assert_eq!(find_part(0, 1).err(), Some(RegistryError::UnknownDay(0)));
+    }
+
+    #[test]
+    fn test_unknown_part() {
+        assert_eq!(find_part(1, 3).err(), Some(RegistryError::UnknownPart(3)));
+    }
+
+    #[test]
+    fn test_day_not_implemented() {
+        assert_eq!(find_part(24, 1).err(), Some(RegistryError::DayNotImplemented(24)));
+    }
+}
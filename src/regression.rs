@@ -0,0 +1,176 @@
+use {
+    std::io,
+    crate::{
+        answers::{AnswerCheck, AnswerStore},
+        registry::{PuzzlePart, PARTS},
+        progress::NoProgress,
+        runner::run_part_on_input,
+        utils::cached_input,
+    },
+};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Verification {
+    Passed,
+    // no answer was accepted yet, but none of the rejected answers rule this one out
+    WithinBounds(String),
+    SkippedNoRecordedAnswer,
+    SkippedInputNotCached,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    KnownWrong {
+        actual: String,
+        check: AnswerCheck,
+    },
+    Failed(String),
+}
+
+impl Verification {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Mismatch { .. } | Self::KnownWrong { .. } | Self::Failed(_))
+    }
+}
+
+impl std::fmt::Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Passed => write!(f, "ok"),
+            Self::WithinBounds(actual) => write!(f, "ok, {} is not ruled out by rejected answers", actual),
+            Self::SkippedNoRecordedAnswer => write!(f, "skipped, no answers recorded"),
+            Self::SkippedInputNotCached => write!(f, "skipped, input is not cached"),
+            Self::Mismatch { expected, actual } => write!(f, "expected {}, got {}", expected, actual),
+            Self::KnownWrong { actual, check } => write!(f, "got {}, but {}", actual, check),
+            Self::Failed(message) => write!(f, "failed: {}", message),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum RegressionError {
+    AnswerHistory(io::Error),
+    PartsFailed(usize),
+}
+
+impl std::fmt::Display for RegressionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AnswerHistory(err) => write!(f, "failed to load answer history: {}", err),
+            Self::PartsFailed(total) => write!(f, "{} part(s) do not match recorded answers", total),
+        }
+    }
+}
+
+impl std::error::Error for RegressionError {}
+
+// re-solves part on cached input and checks it against the accepted answer, or against the rejected ones
+// if none was accepted yet. Never downloads input.
+pub fn verify_part(part: &'static PuzzlePart, answers: &AnswerStore) -> Verification {
+    if !answers.records().iter().any(|record| record.day == part.day && record.part == part.part) {
+        return Verification::SkippedNoRecordedAnswer;
+    }
+    match cached_input(part.day) {
+        Ok(input) => verify_input(part, answers, input.as_deref()),
+        Err(err) => Verification::Failed(err.to_string()),
+    }
+}
+
+fn verify_input(part: &'static PuzzlePart, answers: &AnswerStore, input: Option<&str>) -> Verification {
+    let input = match input {
+        Some(v) => v,
+        None => return Verification::SkippedInputNotCached,
+    };
+
+    let actual = match run_part_on_input(part, input, &NoProgress).outcome {
+        Ok(result) => result.answer,
+        Err(message) => return Verification::Failed(message),
+    };
+    match answers.check(part.day, part.part, &actual) {
+        AnswerCheck::Accepted => Verification::Passed,
+        AnswerCheck::Unknown => Verification::WithinBounds(actual),
+        AnswerCheck::DiffersFromAccepted(expected) => Verification::Mismatch { expected, actual },
+        check => Verification::KnownWrong { actual, check },
+    }
+}
+
+pub fn verify_all() -> Result<(), RegressionError> {
+    let answers = AnswerStore::load_default().map_err(RegressionError::AnswerHistory)?;
+
+    let mut failed = 0;
+    for part in PARTS {
+        let verification = verify_part(part, &answers);
+        if verification.is_failure() {
+            failed += 1;
+        }
+        println!("day {} part {}: {}", part.day, part.part, verification);
+    }
+
+    if failed > 0 {
+        return Err(RegressionError::PartsFailed(failed));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{answers::Verdict, examples::example_input, registry::find_part},
+        super::*,
+    };
+
+    #[test]
+    fn test_recorded_answers() {
+        let answers = AnswerStore::load_default().unwrap();
+
+        for part in PARTS {
+            let verification = verify_part(part, &answers);
+            assert!(!verification.is_failure(), "day {} part {}: {}", part.day, part.part, verification);
+        }
+    }
+
+    #[test]
+    fn test_verify_input() {
+        let part = find_part(7, 1).unwrap();
+        let input = example_input(7, 1);
+        let answers = |records: &str| {
+            let path = std::env::temp_dir().join(format!("aoc-verify-{}.txt", std::process::id()));
+            std::fs::write(&path, records).unwrap();
+            let answers = AnswerStore::load(&path).unwrap();
+            std::fs::remove_file(&path).unwrap();
+            answers
+        };
+
+        assert_eq!(verify_input(part, &answers("7 1 correct 3749\n"), Some(&input)), Verification::Passed);
+        assert_eq!(
+            verify_input(part, &answers("7 1 correct 3750\n"), Some(&input)),
+            Verification::Mismatch {
+                expected: "3750".to_owned(),
+                actual: "3749".to_owned(),
+            }
+        );
+        assert_eq!(
+            verify_input(part, &answers("7 1 too_low 3000\n"), Some(&input)),
+            Verification::WithinBounds("3749".to_owned())
+        );
+        assert_eq!(
+            verify_input(part, &answers("7 1 too_high 3749\n"), Some(&input)),
+            Verification::KnownWrong {
+                actual: "3749".to_owned(),
+                check: AnswerCheck::Rejected(Verdict::TooHigh),
+            }
+        );
+        assert_eq!(
+            verify_input(part, &answers("7 1 too_low 3750\n"), Some(&input)),
+            Verification::KnownWrong {
+                actual: "3749".to_owned(),
+                check: AnswerCheck::BelowLowerBound(3750),
+            }
+        );
+
+        let skipped = verify_input(part, &answers("7 1 correct 3749\n"), None);
+        assert_eq!(skipped, Verification::SkippedInputNotCached);
+        assert!(!skipped.is_failure());
+    }
+}
@@ -0,0 +1,434 @@
+use {
+    std::{
+        any::Any,
+        collections::HashMap,
+        fs::read_to_string,
+        io::{self, Read},
+        panic::{AssertUnwindSafe, catch_unwind},
+        path::{Path, PathBuf},
+        sync::{atomic::{AtomicUsize, Ordering}, mpsc},
+        thread,
+        str::FromStr,
+        time::Duration,
+    },
+    serde::Serialize,
+    crate::{
+        answers::warn_if_known_wrong,
+        progress::{Progress, ProgressMode, ProgressReporter},
+        registry::{PuzzlePart, RegistryError, PARTS, find_part, parts_for_day},
+        solution::PartResult,
+        utils::{DownloadError, download_input},
+    },
+};
+
+#[derive(Debug)]
+pub enum RunnerError {
+    Registry(RegistryError),
+    Download(DownloadError),
+    Input {
+        path: PathBuf,
+        err: io::Error,
+    },
+    PartsFailed(usize),
+}
+
+impl std::fmt::Display for RunnerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Registry(err) => err.fmt(f),
+            Self::Download(err) => err.fmt(f),
+            Self::Input { path, err } => write!(f, "failed to read input from {}: {}", path.display(), err),
+            Self::PartsFailed(total) => write!(f, "{} part(s) failed", total),
+        }
+    }
+}
+
+impl std::error::Error for RunnerError {}
+
+impl From<RegistryError> for RunnerError {
+    fn from(err: RegistryError) -> Self {
+        Self::Registry(err)
+    }
+}
+
+impl From<DownloadError> for RunnerError {
+    fn from(err: DownloadError) -> Self {
+        Self::Download(err)
+    }
+}
+
+pub struct PartReport {
+    pub part: &'static PuzzlePart,
+    // download error or panic message if solution failed
+    pub outcome: Result<PartResult, String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum OutputFormat {
+    Text,
+    // one JSON object per part and line, see PartRecord
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "text" => Self::Text,
+            "json" => Self::Json,
+            other => return Err(format!("unknown output format \"{}\", expected one of: text, json", other)),
+        })
+    }
+}
+
+#[derive(Serialize, Debug, Eq, PartialEq)]
+struct PartRecord<'a> {
+    day: u32,
+    part: u32,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parse_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    solve_ns: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+}
+
+impl<'a> PartRecord<'a> {
+    fn new(report: &'a PartReport) -> Self {
+        let (day, part) = (report.part.day, report.part.part);
+        match &report.outcome {
+            Ok(result) => Self {
+                day,
+                part,
+                status: "ok",
+                answer: Some(&result.answer),
+                parse_ns: Some(result.parse_time.as_nanos()),
+                solve_ns: Some(result.solve_time.as_nanos()),
+                error: None,
+            },
+            Err(message) => Self {
+                day,
+                part,
+                status: "failed",
+                answer: None,
+                parse_ns: None,
+                solve_ns: None,
+                error: Some(message),
+            },
+        }
+    }
+
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("part record is always serializable")
+    }
+}
+
+// runs on given input file ("-" for stdin) instead of the cached one if provided.
+pub fn run(
+    day: u32,
+    part: Option<u32>,
+    input_path: Option<&Path>,
+    format: OutputFormat,
+    progress: ProgressMode,
+) -> Result<(), RunnerError> {
+    let parts = match part {
+        Some(part) => vec![find_part(day, part)?],
+        None => parts_for_day(day)?,
+    };
+
+    let input = match input_path {
+        Some(path) => read_input(path).map_err(|err| RunnerError::Input { path: path.to_owned(), err })?,
+        None => download_input(day)?,
+    };
+
+    let reporter = ProgressReporter::new(progress);
+    let mut failed = 0;
+    for part in parts {
+        if format == OutputFormat::Text {
+            reporter.suspend(|| println!("day {} part {}:", part.day, part.part));
+        }
+        let report = run_part_on_input(part, &input, &*reporter.for_part(part.day, part.part));
+        reporter.suspend(|| {
+            match &report.outcome {
+                // answer history is only relevant for our own input
+                Ok(result) if input_path.is_none() => warn_if_known_wrong(part.day, part.part, &result.answer),
+                Ok(_) => {},
+                Err(_) => failed += 1,
+            }
+
+            match (&report.outcome, format) {
+                (_, OutputFormat::Json) => println!("{}", PartRecord::new(&report).to_json()),
+                (Ok(result), OutputFormat::Text) => println!("result: {}", result.answer),
+                (Err(message), OutputFormat::Text) => println!("failed: {}", message),
+            }
+        });
+    }
+
+    if failed > 0 {
+        return Err(RunnerError::PartsFailed(failed));
+    }
+
+    Ok(())
+}
+
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+
+    read_to_string(path)
+}
+
+// runs parts on up to `jobs` threads, rows are printed in registry order.
+pub fn run_all(jobs: usize, format: OutputFormat, progress: ProgressMode) -> Result<(), RunnerError> {
+    let parts = PARTS.iter().collect::<Vec<_>>();
+    let inputs = load_inputs(&parts);
+
+    if format == OutputFormat::Text {
+        println!("{:>4} {:>5} {:>20} {:>14} {:>14}", "day", "part", "answer", "parse", "solve");
+    }
+
+    let reporter = ProgressReporter::new(progress);
+    let mut failed = 0;
+    run_parts(&parts, &inputs, &reporter, jobs, |report| reporter.suspend(|| {
+        match &report.outcome {
+            Ok(result) => warn_if_known_wrong(report.part.day, report.part.part, &result.answer),
+            Err(_) => failed += 1,
+        }
+
+        match (&report.outcome, format) {
+            (_, OutputFormat::Json) => println!("{}", PartRecord::new(&report).to_json()),
+            (Ok(result), OutputFormat::Text) => println!(
+                "{:>4} {:>5} {:>20} {:>14} {:>14}",
+                report.part.day,
+                report.part.part,
+                result.answer,
+                format_duration(result.parse_time),
+                format_duration(result.solve_time),
+            ),
+            (Err(message), OutputFormat::Text) => {
+                println!("{:>4} {:>5} {:>20} {}", report.part.day, report.part.part, "failed", message);
+            },
+        }
+    }));
+
+    if failed > 0 {
+        return Err(RunnerError::PartsFailed(failed));
+    }
+
+    Ok(())
+}
+
+// inputs are downloaded one by one before running anything, so that parallel jobs never hit the network
+fn load_inputs(parts: &[&'static PuzzlePart]) -> HashMap<u32, Result<String, String>> {
+    let mut inputs = HashMap::new();
+    for part in parts {
+        inputs.entry(part.day).or_insert_with(|| download_input(part.day).map_err(|err| err.to_string()));
+    }
+    inputs
+}
+
+// reports are passed to `on_report` on the calling thread in the order of `parts`, as soon as
+// all preceding parts are done.
+fn run_parts(
+    parts: &[&'static PuzzlePart],
+    inputs: &HashMap<u32, Result<String, String>>,
+    reporter: &ProgressReporter,
+    jobs: usize,
+    mut on_report: impl FnMut(PartReport),
+) {
+    let run = |part: &'static PuzzlePart| match inputs.get(&part.day) {
+        Some(Ok(input)) => run_part_on_input(part, input, &*reporter.for_part(part.day, part.part)),
+        Some(Err(err)) => PartReport {
+            part,
+            outcome: Err(err.clone()),
+        },
+        None => PartReport {
+            part,
+            outcome: Err(format!("input for day {} is not loaded", part.day)),
+        },
+    };
+
+    if jobs <= 1 {
+        parts.iter().for_each(|part| on_report(run(part)));
+        return;
+    }
+
+    let next_part = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(parts.len()) {
+            let sender = sender.clone();
+            let next_part = &next_part;
+            let run = &run;
+            scope.spawn(move || {
+                loop {
+                    let index = next_part.fetch_add(1, Ordering::Relaxed);
+                    let part = match parts.get(index) {
+                        Some(v) => v,
+                        None => return,
+                    };
+                    if sender.send((index, run(part))).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // finished out of order, waiting for preceding parts
+        let mut pending = HashMap::new();
+        let mut next_report = 0;
+        for (index, report) in receiver {
+            pending.insert(index, report);
+            while let Some(report) = pending.remove(&next_report) {
+                on_report(report);
+                next_report += 1;
+            }
+        }
+    });
+}
+
+pub fn run_part_on_input(part: &'static PuzzlePart, input: &str, progress: &dyn Progress) -> PartReport {
+    PartReport {
+        part,
+        // progress reporters are not used after a panic
+        outcome: catch_unwind(AssertUnwindSafe(|| (part.solve)(input, progress))).map_err(panic_message),
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3?}", duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        std::panic::panic_any,
+        super::*,
+    };
+
+    #[test]
+    fn test_panic_message() {
+        assert_eq!(panic_message(catch_unwind(|| panic!("static message")).unwrap_err()), "static message");
+        assert_eq!(panic_message(catch_unwind(|| panic!("formatted {}", 42)).unwrap_err()), "formatted 42");
+        assert_eq!(panic_message(catch_unwind(|| panic_any(42)).unwrap_err()), "unknown panic");
+    }
+
+    fn sleeping_solve(input: &str, _progress: &dyn Progress) -> PartResult {
+        std::thread::sleep(Duration::from_millis(input.len() as u64 * 20));
+        PartResult {
+            answer: input.len().to_string(),
+            parse_time: Duration::ZERO,
+            solve_time: Duration::ZERO,
+        }
+    }
+
+    fn failing_solve(_input: &str, _progress: &dyn Progress) -> PartResult {
+        panic!("not implemented")
+    }
+
+    static TEST_PARTS: [PuzzlePart; 5] = [
+        PuzzlePart::new(1, 1, sleeping_solve),
+        PuzzlePart::new(1, 2, failing_solve),
+        PuzzlePart::new(2, 1, sleeping_solve),
+        PuzzlePart::new(3, 1, sleeping_solve),
+        PuzzlePart::new(3, 2, sleeping_solve),
+    ];
+
+    #[test]
+    fn test_run_parts_in_order() {
+        let parts = TEST_PARTS.iter().collect::<Vec<_>>();
+        // day 1 is slowest, so later parts finish first when running in parallel
+        let inputs = HashMap::from([
+            (1, Ok("xxxxx".to_owned())),
+            (2, Ok("x".to_owned())),
+            (3, Err("input for day 3 is not cached".to_owned())),
+        ]);
+
+        for jobs in [1, 4] {
+            let mut reports = Vec::new();
+            run_parts(&parts, &inputs, &ProgressReporter::new(ProgressMode::None), jobs, |report| reports.push((
+                report.part.day,
+                report.part.part,
+                report.outcome.map(|result| result.answer),
+            )));
+
+            assert_eq!(reports, vec![
+                (1, 1, Ok("5".to_owned())),
+                (1, 2, Err("not implemented".to_owned())),
+                (2, 1, Ok("1".to_owned())),
+                (3, 1, Err("input for day 3 is not cached".to_owned())),
+                (3, 2, Err("input for day 3 is not cached".to_owned())),
+            ], "jobs = {}", jobs);
+        }
+    }
+
+    #[test]
+    fn test_part_record_json() {
+        let ok = PartReport {
+            part: &TEST_PARTS[0],
+            outcome: Ok(PartResult {
+                answer: "143".to_owned(),
+                parse_time: Duration::from_micros(12),
+                solve_time: Duration::from_nanos(3400),
+            }),
+        };
+        assert_eq!(
+            PartRecord::new(&ok).to_json(),
+            r#"{"day":1,"part":1,"status":"ok","answer":"143","parse_ns":12000,"solve_ns":3400}"#
+        );
+
+        let failed = PartReport {
+            part: &TEST_PARTS[1],
+            outcome: Err("not implemented".to_owned()),
+        };
+        assert_eq!(
+            PartRecord::new(&failed).to_json(),
+            r#"{"day":1,"part":2,"status":"failed","error":"not implemented"}"#
+        );
+    }
+
+    #[test]
+    fn test_run_failing_part() {
+        let path = std::env::temp_dir().join(format!("aoc-invalid-input-{}.txt", std::process::id()));
+        std::fs::write(&path, "190 10 19\n").unwrap();
+        let result = run(7, None, Some(&path), OutputFormat::Json, ProgressMode::None);
+        std::fs::remove_file(&path).unwrap();
+
+        // both parts are reported even though the first one failed
+        assert!(matches!(result, Err(RunnerError::PartsFailed(2))));
+    }
+
+    #[test]
+    fn test_read_input() {
+        let path = std::env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+        std::fs::write(&path, "3   4\n4   3\n").unwrap();
+        let input = read_input(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(input.unwrap(), "3   4\n4   3\n");
+        assert!(read_input(Path::new("/nonexistent/input.txt")).is_err());
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.500ms");
+    }
+}
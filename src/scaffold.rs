@@ -0,0 +1,264 @@
+use {
+    std::{fs::{create_dir, read_to_string, write, exists}, io, path::{Path, PathBuf}},
+    crate::registry::{RegistryError, TOTAL_DAYS},
+};
+
+pub const SOURCE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Registry(RegistryError),
+    ModuleExists(PathBuf),
+    AlreadyRegistered {
+        day: u32,
+        file: PathBuf,
+    },
+    MissingAnchor {
+        file: PathBuf,
+        anchor: &'static str,
+    },
+    Io(io::Error),
+}
+
+impl std::fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Registry(err) => err.fmt(f),
+            Self::ModuleExists(path) => write!(f, "module {} already exists", path.display()),
+            Self::AlreadyRegistered { day, file } => write!(f, "day {} is already registered in {}", day, file.display()),
+            Self::MissingAnchor { file, anchor } => write!(f, "can not find where to register new day in {}, expected lines like \"{}\"", file.display(), anchor),
+            Self::Io(err) => write!(f, "failed to write module: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+impl From<RegistryError> for ScaffoldError {
+    fn from(err: RegistryError) -> Self {
+        Self::Registry(err)
+    }
+}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+// creates src/dayNN with a Solution skeleton and registers it in main.rs and registry.rs.
+// returns paths of created and modified files.
+pub fn scaffold(source_dir: &Path, day: u32) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if day == 0 || day > TOTAL_DAYS {
+        return Err(RegistryError::UnknownDay(day).into());
+    }
+
+    let module_dir = source_dir.join(format!("day{:02}", day));
+    if exists(&module_dir)? {
+        return Err(ScaffoldError::ModuleExists(module_dir));
+    }
+
+    // both files are updated in memory first, so that nothing is written if registration fails
+    let main_path = source_dir.join("main.rs");
+    let main = register_module(&main_path, &read_to_string(&main_path)?, day)?;
+    let registry_path = source_dir.join("registry.rs");
+    let registry = register_parts(&registry_path, &read_to_string(&registry_path)?, day)?;
+
+    create_dir(&module_dir)?;
+    let mut files = Vec::new();
+    for (name, content) in [
+        ("mod.rs", module_template(day)),
+        ("part1.rs", part_template(day, 1)),
+        ("part2.rs", part_template(day, 2)),
+    ] {
+        let path = module_dir.join(name);
+        write(&path, content)?;
+        files.push(path);
+    }
+
+    write(&main_path, main)?;
+    write(&registry_path, registry)?;
+    files.extend([main_path, registry_path]);
+
+    Ok(files)
+}
+
+fn register_module(path: &Path, main: &str, day: u32) -> Result<String, ScaffoldError> {
+    insert_sorted(path, main, "mod dayNN;", day, |line| parse_day(line.strip_prefix("mod day")?.strip_suffix(';')?), &[
+        format!("mod day{:02};", day),
+    ])
+}
+
+fn register_parts(path: &Path, registry: &str, day: u32) -> Result<String, ScaffoldError> {
+    let registry = insert_sorted(path, registry, "    dayNN::DayNN,", day, |line| parse_day(line.strip_prefix("    day")?.split("::").next()?), &[
+        format!("    day{:02}::Day{:02},", day, day),
+    ])?;
+
+    insert_sorted(path, &registry, "    PuzzlePart::new(N, M, ...),", day, |line| parse_day(line.strip_prefix("    PuzzlePart::new(")?.split(',').next()?), &[
+        format!("    PuzzlePart::new({}, 1, solve_part1::<Day{:02}>),", day, day),
+        format!("    PuzzlePart::new({}, 2, solve_part2::<Day{:02}>),", day, day),
+    ])
+}
+
+fn parse_day(s: &str) -> Option<u32> {
+    s.parse().ok()
+}
+
+// inserts new lines next to existing lines for other days, keeping them ordered by day
+fn insert_sorted(
+    path: &Path,
+    source: &str,
+    anchor: &'static str,
+    day: u32,
+    line_day: impl Fn(&str) -> Option<u32>,
+    new_lines: &[String],
+) -> Result<String, ScaffoldError> {
+    let mut lines = source.lines().collect::<Vec<_>>();
+
+    let days = lines.iter()
+        .enumerate()
+        .filter_map(|(index, line)| line_day(line).map(|line_day| (index, line_day)))
+        .collect::<Vec<_>>();
+    if days.iter().any(|(_, line_day)| *line_day == day) {
+        return Err(ScaffoldError::AlreadyRegistered { day, file: path.to_owned() });
+    }
+
+    let index = match (days.iter().rev().find(|(_, line_day)| *line_day < day), days.first()) {
+        (Some((index, _)), _) => index + 1,
+        (None, Some((index, _))) => *index,
+        (None, None) => return Err(ScaffoldError::MissingAnchor { file: path.to_owned(), anchor }),
+    };
+    for (offset, line) in new_lines.iter().enumerate() {
+        lines.insert(index + offset, line);
+    }
+
+    let mut result = lines.join("\n");
+    if source.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+fn module_template(day: u32) -> String {
+    format!(r#"use crate::solution::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    type Input = Vec<String>;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Self::Input {{
+        parse_input(input)
+    }}
+
+    fn part1(input: &Self::Input) -> Self::Output1 {{
+        part1::solve(input)
+    }}
+
+    fn part2(input: &Self::Input) -> Self::Output2 {{
+        part2::solve(input)
+    }}
+}}
+
+pub fn parse_input(input: &str) -> Vec<String> {{
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_owned())
+        .collect()
+}}
+"#)
+}
+
+// unsolved parts panic, so that the runner reports them as failed and no placeholder answer is ever submitted
+fn part_template(day: u32, part: u32) -> String {
+    format!(r#"pub fn solve(input: &[String]) -> u64 {{
+    todo!("day {day} part {part}, input has {{}} lines", input.len())
+}}
+
+#[cfg(test)]
+mod tests {{
+    use {{
+        crate::examples::example_input,
+        super::{{
+            *,
+            super::parse_input,
+        }},
+    }};
+
+    #[test]
+    #[ignore = "save examples with `aoc examples {day}` and fill in expected answer"]
+    fn test_example() {{
+        assert_eq!(solve(&parse_input(&example_input({day}, 1))), 0);
+    }}
+}}
+"#)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAIN: &str = "mod day01;\nmod day02;\nmod day05;\n\nmod answers;\n";
+    const REGISTRY: &str = "use crate::{\n    day01::Day01,\n    day05::Day05,\n    solution::{PartResult, solve_part1, solve_part2},\n};\n\npub const PARTS: &[PuzzlePart] = &[\n    PuzzlePart::new(1, 1, solve_part1::<Day01>),\n    PuzzlePart::new(1, 2, solve_part2::<Day01>),\n    PuzzlePart::new(5, 1, solve_part1::<Day05>),\n];\n";
+
+    #[test]
+    fn test_register_module() {
+        assert_eq!(
+            register_module(Path::new("main.rs"), MAIN, 3).unwrap(),
+            "mod day01;\nmod day02;\nmod day03;\nmod day05;\n\nmod answers;\n"
+        );
+        assert_eq!(
+            register_module(Path::new("main.rs"), MAIN, 13).unwrap(),
+            "mod day01;\nmod day02;\nmod day05;\nmod day13;\n\nmod answers;\n"
+        );
+        assert!(matches!(
+            register_module(Path::new("main.rs"), MAIN, 5),
+            Err(ScaffoldError::AlreadyRegistered { day: 5, .. })
+        ));
+        assert!(matches!(
+            register_module(Path::new("main.rs"), "mod answers;\n", 5),
+            Err(ScaffoldError::MissingAnchor { .. })
+        ));
+    }
+
+    #[test]
+    fn test_register_parts() {
+        assert_eq!(
+            register_parts(Path::new("registry.rs"), REGISTRY, 2).unwrap(),
+            "use crate::{\n    day01::Day01,\n    day02::Day02,\n    day05::Day05,\n    solution::{PartResult, solve_part1, solve_part2},\n};\n\npub const PARTS: &[PuzzlePart] = &[\n    PuzzlePart::new(1, 1, solve_part1::<Day01>),\n    PuzzlePart::new(1, 2, solve_part2::<Day01>),\n    PuzzlePart::new(2, 1, solve_part1::<Day02>),\n    PuzzlePart::new(2, 2, solve_part2::<Day02>),\n    PuzzlePart::new(5, 1, solve_part1::<Day05>),\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let source_dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(&source_dir).unwrap();
+        write(source_dir.join("main.rs"), MAIN).unwrap();
+        write(source_dir.join("registry.rs"), REGISTRY).unwrap();
+
+        let files = scaffold(&source_dir, 13).unwrap();
+        let second_attempt = scaffold(&source_dir, 13);
+        let part1 = read_to_string(source_dir.join("day13/part1.rs")).unwrap();
+        let module = read_to_string(source_dir.join("day13/mod.rs")).unwrap();
+        let main = read_to_string(source_dir.join("main.rs")).unwrap();
+        std::fs::remove_dir_all(&source_dir).unwrap();
+
+        assert_eq!(files.len(), 5);
+        assert!(matches!(second_attempt, Err(ScaffoldError::ModuleExists(_))));
+        assert!(module.contains("impl Solution for Day13 {"));
+        assert!(part1.contains("example_input(13, 1)"));
+        assert!(part1.contains("todo!(\"day 13 part 1"));
+        assert!(main.contains("mod day13;\n"));
+    }
+
+    #[test]
+    fn test_scaffold_unknown_day() {
+        assert!(matches!(scaffold(Path::new(SOURCE_DIR), 26), Err(ScaffoldError::Registry(RegistryError::UnknownDay(26)))));
+    }
+}
@@ -0,0 +1,246 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+// everything reachable from the start node. Distance is the number of steps for bfs, depth in the
+// search tree for dfs and total cost for dijkstra.
+#[derive(Debug)]
+pub struct SearchResult<N> {
+    pub distances: HashMap<N, u64>,
+    // node each node was first reached from, start has none
+    pub predecessors: HashMap<N, N>,
+    // in the order nodes were visited, starting with start
+    pub visited: Vec<N>,
+}
+
+impl<N: Eq + Hash + Clone> SearchResult<N> {
+    fn new(start: &N) -> Self {
+        Self {
+            distances: HashMap::from([(start.clone(), 0)]),
+            predecessors: HashMap::new(),
+            visited: Vec::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    // from start to target, both included
+    #[allow(dead_code)]
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(target) {
+            return None;
+        }
+
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+// shortest paths by number of steps
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(&start);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        let distance = result.distances[&node];
+        for neighbour in neighbours(&node) {
+            if result.distances.contains_key(&neighbour) {
+                continue;
+            }
+            result.distances.insert(neighbour.clone(), distance + 1);
+            result.predecessors.insert(neighbour.clone(), node.clone());
+            queue.push_back(neighbour);
+        }
+        result.visited.push(node);
+    }
+
+    result
+}
+
+// iterative, so that large regions don't overflow the stack
+pub fn dfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(&start);
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        let distance = result.distances[&node];
+        result.visited.push(node.clone());
+
+        let mut unvisited = neighbours(&node).into_iter()
+            .filter(|neighbour| !result.distances.contains_key(neighbour))
+            .collect::<Vec<_>>();
+        for neighbour in &unvisited {
+            result.distances.insert(neighbour.clone(), distance + 1);
+            result.predecessors.insert(neighbour.clone(), node.clone());
+        }
+        // first neighbour is explored first
+        unvisited.reverse();
+        stack.extend(unvisited);
+    }
+
+    result
+}
+
+// shortest paths when steps have different non-negative costs
+#[allow(dead_code)]
+pub fn dijkstra<N, I>(start: N, neighbours: impl FnMut(&N) -> I) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    best_first(start, neighbours, |_| 0, |_| false).0
+}
+
+// shortest path to the first node for which is_goal is true, as its cost and the path including start.
+// heuristic must never overestimate remaining cost, otherwise the path may not be the shortest.
+#[allow(dead_code)]
+pub fn astar<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> u64,
+    is_goal: impl Fn(&N) -> bool,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (result, goal) = best_first(start, neighbours, heuristic, is_goal);
+    let goal = goal?;
+    let cost = result.distance(&goal)?;
+
+    Some((cost, result.path_to(&goal)?))
+}
+
+fn best_first<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> u64,
+    is_goal: impl Fn(&N) -> bool,
+) -> (SearchResult<N>, Option<N>)
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut result = SearchResult::new(&start);
+    // nodes are referenced by index, so that they don't need to implement Ord
+    let mut nodes = vec![start];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&nodes[0]), 0, 0))]);
+
+    while let Some(Reverse((_estimate, distance, index))) = queue.pop() {
+        let node = nodes[index].clone();
+        if result.distances[&node] < distance {
+            // already reached by a cheaper path
+            continue;
+        }
+        result.visited.push(node.clone());
+        if is_goal(&node) {
+            return (result, Some(node));
+        }
+
+        for (neighbour, cost) in neighbours(&node) {
+            let neighbour_distance = distance + cost;
+            if result.distances.get(&neighbour).is_some_and(|known| *known <= neighbour_distance) {
+                continue;
+            }
+            result.distances.insert(neighbour.clone(), neighbour_distance);
+            result.predecessors.insert(neighbour.clone(), node.clone());
+            queue.push(Reverse((neighbour_distance + heuristic(&neighbour), neighbour_distance, nodes.len())));
+            nodes.push(neighbour);
+        }
+    }
+
+    (result, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{grid::Grid, point::Point},
+        super::*,
+    };
+
+    // S to E, walls are #
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#..E
+.##...#.
+";
+
+    fn maze() -> (Grid<char>, Point, Point) {
+        let grid = Grid::parse(MAZE, |c| c);
+        let find = |target| grid.cells().find(|(_, c)| **c == target).unwrap().0;
+        let (start, end) = (find('S'), find('E'));
+        (grid, start, end)
+    }
+
+    fn open_neighbours(grid: &Grid<char>, point: Point) -> Vec<Point> {
+        grid.neighbours4(point).filter(|neighbour| grid.get(*neighbour) != Some(&'#')).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, end) = maze();
+        let result = bfs(start, |point| open_neighbours(&grid, *point));
+
+        assert_eq!(result.distance(&end), Some(11));
+        let path = result.path_to(&end).unwrap();
+        assert_eq!((path.first(), path.last(), path.len()), (Some(&start), Some(&end), 12));
+        assert_eq!(result.visited.len(), grid.cells().filter(|(_, c)| **c != '#').count());
+        assert_eq!(result.path_to(&Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_dfs() {
+        let result = dfs(0, |node| if *node < 3 { vec![node + 1, node + 10] } else { vec![] });
+
+        assert_eq!(result.visited, vec![0, 1, 2, 3, 12, 11, 10]);
+        assert_eq!(result.distance(&12), Some(3));
+        assert_eq!(result.path_to(&12), Some(vec![0, 1, 2, 12]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // direct edge is more expensive than going around
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('d', 10)]),
+            ('b', vec![('c', 2)]),
+            ('c', vec![('d', 3)]),
+        ]);
+        let result = dijkstra('a', |node| edges.get(node).cloned().unwrap_or_default());
+
+        assert_eq!(result.distance(&'d'), Some(6));
+        assert_eq!(result.path_to(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+    }
+
+    #[test]
+    fn test_astar() {
+        let (grid, start, end) = maze();
+        let (cost, path) = astar(
+            start,
+            |point| open_neighbours(&grid, *point).into_iter().map(|neighbour| (neighbour, 1)),
+            |point| point.manhattan_distance(&end) as u64,
+            |point| *point == end,
+        ).unwrap();
+
+        assert_eq!((cost, path.len()), (11, 12));
+        assert!(astar(start, |point| open_neighbours(&grid, *point).into_iter().map(|n| (n, 1)), |_| 0, |point| point.x > 10).is_none());
+    }
+}
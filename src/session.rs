@@ -0,0 +1,195 @@
+use {
+    std::{env::var, fs::{create_dir_all, read_to_string, write, exists}, io, path::{Path, PathBuf}, sync::OnceLock},
+    crate::{
+        http::{self, HttpClient},
+        utils::{DownloadError, cache_input, fetch_input},
+    },
+};
+
+const SESSION_ENV: &str = "SESSION_COOKIE";
+
+// validated at most once per run, before the first request that needs it
+static VALIDATED_SESSION: OnceLock<String> = OnceLock::new();
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SessionSource {
+    Env,
+    File(PathBuf),
+}
+
+impl std::fmt::Display for SessionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Env => write!(f, "{} env variable", SESSION_ENV),
+            Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SessionError {
+    Missing {
+        path: Option<PathBuf>,
+    },
+    Unreadable {
+        path: PathBuf,
+        err: io::Error,
+    },
+}
+
+impl std::fmt::Display for SessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { path: Some(path) } => write!(
+                f,
+                "session cookie is not set, save it with `aoc session set` (stored in {}) or set {}",
+                path.display(),
+                SESSION_ENV
+            ),
+            Self::Missing { path: None } => write!(f, "session cookie is not set, set {} env variable", SESSION_ENV),
+            Self::Unreadable { path, err } => write!(f, "failed to read session cookie from {}: {}", path.display(), err),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+// $XDG_CONFIG_HOME/aoc/session or ~/.config/aoc/session
+pub fn session_path() -> Option<PathBuf> {
+    let env = |name: &str| var(name).ok().filter(|v| !v.is_empty()).map(PathBuf::from);
+    let config_dir = env("XDG_CONFIG_HOME").or_else(|| env("HOME").map(|home| home.join(".config")))?;
+    Some(config_dir.join("aoc").join("session"))
+}
+
+// SESSION_COOKIE env variable takes priority over the session file.
+pub fn load() -> Result<(String, SessionSource), SessionError> {
+    load_from(var(SESSION_ENV).ok(), session_path().as_deref())
+}
+
+fn load_from(env: Option<String>, path: Option<&Path>) -> Result<(String, SessionSource), SessionError> {
+    if let Some(session) = env.map(|v| v.trim().to_owned()).filter(|v| !v.is_empty()) {
+        return Ok((session, SessionSource::Env));
+    }
+
+    let path = match path {
+        Some(v) => v,
+        None => return Err(SessionError::Missing { path: None }),
+    };
+    let unreadable = |err| SessionError::Unreadable { path: path.to_owned(), err };
+    if !exists(path).map_err(unreadable)? {
+        return Err(SessionError::Missing { path: Some(path.to_owned()) });
+    }
+
+    let session = read_to_string(path).map_err(unreadable)?.trim().to_owned();
+    if session.is_empty() {
+        return Err(SessionError::Missing { path: Some(path.to_owned()) });
+    }
+
+    Ok((session, SessionSource::File(path.to_owned())))
+}
+
+pub fn save(session: &str) -> Result<PathBuf, SessionError> {
+    let path = session_path().ok_or(SessionError::Missing { path: None })?;
+    save_to(&path, session)?;
+    Ok(path)
+}
+
+fn save_to(path: &Path, session: &str) -> Result<(), SessionError> {
+    let unreadable = |err| SessionError::Unreadable { path: path.to_owned(), err };
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(unreadable)?;
+    }
+    write(path, format!("{}\n", session.trim())).map_err(unreadable)?;
+
+    // the cookie gives full access to the account, keep it private
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600)).map_err(unreadable)?;
+    }
+
+    Ok(())
+}
+
+// cheap authenticated request: input for day 1 is always unlocked for past years and is small. It is cached,
+// so that it is not downloaded again. Fails with DownloadError::NotLoggedIn if the cookie has expired.
+pub fn validate(client: &HttpClient, session: &str) -> Result<(), DownloadError> {
+    match fetch_input(client, session, 1) {
+        Ok(input) => cache_input(client.config(), 1, &input),
+        Err(DownloadError::PuzzleNotYetUnlocked { .. }) => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
+// session cookie that was checked against advent of code website during this run.
+pub fn validated() -> Result<String, DownloadError> {
+    if let Some(session) = VALIDATED_SESSION.get() {
+        return Ok(session.clone());
+    }
+
+    let (session, _source) = load().map_err(DownloadError::Session)?;
+    validate(http::client()?, &session)?;
+
+    Ok(VALIDATED_SESSION.get_or_init(|| session).clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{config::Config, http::test_client_with, test_server::TestServer},
+        super::*,
+    };
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc-session-{}-{}", name, std::process::id())).join("session")
+    }
+
+    #[test]
+    fn test_env_takes_priority() {
+        assert_eq!(
+            load_from(Some("from-env\n".to_owned()), Some(Path::new("/nonexistent/session"))).unwrap(),
+            ("from-env".to_owned(), SessionSource::Env)
+        );
+    }
+
+    #[test]
+    fn test_missing_session() {
+        assert!(matches!(
+            load_from(None, Some(Path::new("/nonexistent/session"))),
+            Err(SessionError::Missing { path: Some(_) })
+        ));
+        assert!(matches!(load_from(Some(String::new()), None), Err(SessionError::Missing { path: None })));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = temp_path("save");
+        save_to(&path, " 53616c7465645f5f \n").unwrap();
+        let loaded = load_from(None, Some(&path));
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+
+        assert_eq!(loaded.unwrap(), ("53616c7465645f5f".to_owned(), SessionSource::File(path)));
+    }
+
+    #[test]
+    fn test_validate() {
+        let server = TestServer::start(vec![
+            (200, "3   4\n4   3\n"),
+            (400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+        ]);
+        let cache_dir = temp_path("validate").with_file_name("cache");
+        let client = test_client_with(Config {
+            base_url: server.base_url.clone(),
+            cache_dir: cache_dir.clone(),
+            ..Config::default()
+        });
+
+        assert!(validate(&client, "valid").is_ok());
+        assert!(matches!(validate(&client, "expired"), Err(DownloadError::NotLoggedIn)));
+        assert_eq!(server.requests()[1].path, "/2024/day/1/input");
+
+        let cached = read_to_string(cache_dir.join("2024/day_1_input.txt"));
+        std::fs::remove_dir_all(cache_dir.parent().unwrap()).unwrap();
+        assert_eq!(cached.unwrap(), "3   4\n4   3\n");
+    }
+}
@@ -0,0 +1,74 @@
+use {
+    std::{fmt::Display, time::{Duration, Instant}},
+    crate::progress::Progress,
+};
+
+pub trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+
+    // long-running parts override these to report progress
+    fn part1_with_progress(input: &Self::Input, _progress: &dyn Progress) -> Self::Output1 {
+        Self::part1(input)
+    }
+
+    fn part2_with_progress(input: &Self::Input, _progress: &dyn Progress) -> Self::Output2 {
+        Self::part2(input)
+    }
+}
+
+pub struct PartResult {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+pub fn solve_part1<S: Solution>(input: &str, progress: &dyn Progress) -> PartResult {
+    timed(input, S::parse, |input| S::part1_with_progress(input, progress).to_string())
+}
+
+pub fn solve_part2<S: Solution>(input: &str, progress: &dyn Progress) -> PartResult {
+    timed(input, S::parse, |input| S::part2_with_progress(input, progress).to_string())
+}
+
+fn timed<T>(input: &str, parse: impl FnOnce(&str) -> T, solve: impl FnOnce(&T) -> String) -> PartResult {
+    let started_at = Instant::now();
+    let input = parse(input);
+    let parse_time = started_at.elapsed();
+
+    let started_at = Instant::now();
+    let answer = solve(&input);
+    let solve_time = started_at.elapsed();
+
+    PartResult {
+        answer,
+        parse_time,
+        solve_time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{day01::Day01, progress::NoProgress},
+        super::*,
+    };
+
+    const EXAMPLE: &str = r#"3   4
+4   3
+2   5
+1   3
+3   9
+3   3"#;
+
+    #[test]
+    fn test_solve_parts() {
+        assert_eq!(solve_part1::<Day01>(EXAMPLE, &NoProgress).answer, "11");
+        assert_eq!(solve_part2::<Day01>(EXAMPLE, &NoProgress).answer, "31");
+    }
+}
@@ -0,0 +1,276 @@
+use {
+    std::{io, time::Duration},
+    crate::{
+        answers::{AnswerCheck, AnswerStore, Verdict},
+        config,
+        http::{self, HttpClient},
+        progress::NoProgress,
+        registry::{PuzzlePart, RegistryError, find_part},
+        runner::run_part_on_input,
+        session,
+        utils::{DownloadError, download_input},
+    },
+};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(Duration),
+    AlreadySolved,
+}
+
+impl std::fmt::Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "that's the right answer"),
+            Self::TooHigh => write!(f, "answer is too high"),
+            Self::TooLow => write!(f, "answer is too low"),
+            Self::Wrong => write!(f, "that's not the right answer"),
+            Self::RateLimited(wait) => write!(f, "answer submitted too recently, wait {}s before trying again", wait.as_secs()),
+            Self::AlreadySolved => write!(f, "this part is already solved"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Registry(RegistryError),
+    Download(DownloadError),
+    PartFailed(String),
+    AnswerHistory(io::Error),
+    KnownWrongAnswer(AnswerCheck),
+    Offline,
+    Request(reqwest::Error),
+    UnexpectedResponse(String),
+}
+
+impl std::fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Registry(err) => err.fmt(f),
+            Self::Download(err) => err.fmt(f),
+            Self::PartFailed(message) => write!(f, "refusing to submit, part failed: {}", message),
+            Self::AnswerHistory(err) => write!(f, "failed to access answer history: {}", err),
+            Self::KnownWrongAnswer(check) => write!(f, "refusing to submit: {} (use --force to submit anyway)", check),
+            Self::Offline => write!(f, "answers can not be submitted in offline mode"),
+            Self::Request(err) => write!(f, "failed to submit answer: {}", err),
+            Self::UnexpectedResponse(message) => write!(f, "unexpected response from advent of code website: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<RegistryError> for SubmitError {
+    fn from(err: RegistryError) -> Self {
+        Self::Registry(err)
+    }
+}
+
+impl From<DownloadError> for SubmitError {
+    fn from(err: DownloadError) -> Self {
+        Self::Download(err)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(err: io::Error) -> Self {
+        Self::AnswerHistory(err)
+    }
+}
+
+impl From<reqwest::Error> for SubmitError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Request(err)
+    }
+}
+
+// submits given answer, or the one computed by solution if answer is not provided.
+// answers known to be wrong from answer history are not submitted unless forced.
+pub fn submit(day: u32, part: u32, answer: Option<String>, force: bool) -> Result<SubmissionOutcome, SubmitError> {
+    let puzzle_part = find_part(day, part)?;
+    let answer = match answer {
+        Some(v) => v,
+        None => solve_for_submission(puzzle_part, &download_input(day)?)?,
+    };
+
+    let mut answers = AnswerStore::load_default()?;
+    let check = answers.check(day, part, &answer);
+    if check.is_known_wrong() {
+        if !force {
+            return Err(SubmitError::KnownWrongAnswer(check));
+        }
+        eprintln!("warning: {}", check);
+    }
+
+    println!("submitting answer {} for day {} part {}", answer, day, part);
+    let outcome = submit_answer(day, part, &answer)?;
+    println!("{}", outcome);
+
+    if let Some(verdict) = Verdict::for_outcome(&outcome) {
+        answers.record(day, part, &answer, verdict)?;
+    }
+
+    Ok(outcome)
+}
+
+// unsolved parts panic, their answer must never be submitted
+fn solve_for_submission(part: &'static PuzzlePart, input: &str) -> Result<String, SubmitError> {
+    run_part_on_input(part, input, &NoProgress).outcome
+        .map(|result| result.answer)
+        .map_err(SubmitError::PartFailed)
+}
+
+pub fn submit_answer(day: u32, part: u32, answer: &str) -> Result<SubmissionOutcome, SubmitError> {
+    if config::get().offline {
+        return Err(SubmitError::Offline);
+    }
+
+    let session = session::validated()?;
+    submit_answer_to(http::client().map_err(DownloadError::Config)?, &session, day, part, answer)
+}
+
+pub fn submit_answer_to(client: &HttpClient, session: &str, day: u32, part: u32, answer: &str) -> Result<SubmissionOutcome, SubmitError> {
+    let response = client.post_form(
+        &format!("{}/answer", client.config().day_url(day)),
+        session,
+        &[("level", part.to_string()), ("answer", answer.to_owned())],
+    )?.body;
+
+    classify_response(&response).ok_or_else(|| SubmitError::UnexpectedResponse(article_text(&response)))
+}
+
+pub fn classify_response(html: &str) -> Option<SubmissionOutcome> {
+    Some(if html.contains("That's the right answer") {
+        SubmissionOutcome::Correct
+    } else if html.contains("You gave an answer too recently") {
+        SubmissionOutcome::RateLimited(parse_wait_time(html).unwrap_or_default())
+    } else if html.contains("Did you already complete it?") {
+        SubmissionOutcome::AlreadySolved
+    } else if html.contains("your answer is too high") {
+        SubmissionOutcome::TooHigh
+    } else if html.contains("your answer is too low") {
+        SubmissionOutcome::TooLow
+    } else if html.contains("That's not the right answer") {
+        SubmissionOutcome::Wrong
+    } else {
+        return None;
+    })
+}
+
+// parses "You have 1m 23s left to wait."
+fn parse_wait_time(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    let mut total = 0;
+    for component in html[start..end].split_whitespace() {
+        let (value, seconds_per_unit) = if let Some(value) = component.strip_suffix('h') {
+            (value, 60 * 60)
+        } else if let Some(value) = component.strip_suffix('m') {
+            (value, 60)
+        } else {
+            (component.strip_suffix('s')?, 1)
+        };
+        total += value.parse::<u64>().ok()? * seconds_per_unit;
+    }
+
+    Some(Duration::from_secs(total))
+}
+
+// text of the <article> element, or the whole response if there is none.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article>"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut inside_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => inside_tag = true,
+            '>' => inside_tag = false,
+            _ if !inside_tag => text.push(c),
+            _ => {},
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{http::test_client, test_server::TestServer},
+        super::*,
+    };
+
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian. <a href=\"/2024/day/6#part2\">[Continue to Part Two]</a></p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article></main>";
+    const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again. <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article></main>";
+    const WRONG: &str = "<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article></main>";
+    const RATE_LIMITED: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait. <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article></main>";
+    const ALREADY_SOLVED: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article></main>";
+
+    #[test]
+    fn test_classify_response() {
+        assert_eq!(classify_response(CORRECT), Some(SubmissionOutcome::Correct));
+        assert_eq!(classify_response(TOO_HIGH), Some(SubmissionOutcome::TooHigh));
+        assert_eq!(classify_response(TOO_LOW), Some(SubmissionOutcome::TooLow));
+        assert_eq!(classify_response(WRONG), Some(SubmissionOutcome::Wrong));
+        assert_eq!(classify_response(RATE_LIMITED), Some(SubmissionOutcome::RateLimited(Duration::from_secs(83))));
+        assert_eq!(classify_response(ALREADY_SOLVED), Some(SubmissionOutcome::AlreadySolved));
+        assert_eq!(classify_response("<html>maintenance</html>"), None);
+    }
+
+    #[test]
+    fn test_parse_wait_time() {
+        assert_eq!(parse_wait_time("You have 42s left to wait."), Some(Duration::from_secs(42)));
+        assert_eq!(parse_wait_time("You have 5m 0s left to wait."), Some(Duration::from_secs(300)));
+        assert_eq!(parse_wait_time("Please wait."), None);
+    }
+
+    #[test]
+    fn test_article_text() {
+        assert_eq!(
+            article_text(ALREADY_SOLVED),
+            "You don't seem to be solving the right level. Did you already complete it? [Return to Day 6]"
+        );
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let server = TestServer::start(vec![(200, TOO_LOW)]);
+        assert_eq!(
+            submit_answer_to(&test_client(&server.base_url), "test-session", 6, 2, "1605").unwrap(),
+            SubmissionOutcome::TooLow
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2024/day/6/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+        assert_eq!(requests[0].body, "level=2&answer=1605");
+    }
+
+    #[test]
+    fn test_submit_answer_unexpected_response() {
+        let server = TestServer::start(vec![(200, "<article><p>Something <em>new</em></p></article>")]);
+        match submit_answer_to(&test_client(&server.base_url), "test-session", 6, 2, "1605") {
+            Err(SubmitError::UnexpectedResponse(message)) => assert_eq!(message, "Something new"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_solve_for_submission() {
+        let part = find_part(7, 1).unwrap();
+        assert_eq!(solve_for_submission(part, "190: 10 19\n").unwrap(), "190");
+        assert!(matches!(solve_for_submission(part, "190 10 19\n"), Err(SubmitError::PartFailed(_))));
+    }
+}
@@ -1,21 +1,369 @@
-use std::{env::var, fs::{read_to_string, write, exists}};
+use {
+    std::{
+        fs::{create_dir_all, read_to_string, remove_file, rename, write, exists},
+        io,
+        path::{Path, PathBuf},
+    },
+    crate::{
+        config::{self, Config, ConfigError},
+        http::{self, HttpClient, HttpResponse},
+        registry::TOTAL_DAYS,
+        session::{self, SessionError},
+    },
+};
 
-pub fn download_input(day: u32) -> String {
-    let cached_path = format!("data/day_{}_input.txt", day);
-    if exists(&cached_path).unwrap() {
-        return read_to_string(cached_path).unwrap();
+#[derive(Debug)]
+pub enum DownloadError {
+    Offline {
+        day: u32,
+    },
+    Session(SessionError),
+    Config(ConfigError),
+    CacheDirUnavailable(io::Error),
+    Request(reqwest::Error),
+    Http {
+        status: u16,
+    },
+    PuzzleNotYetUnlocked {
+        day: u32,
+    },
+    NotLoggedIn,
+    InvalidInput(InputProblem),
+    InvalidCachedInput {
+        day: u32,
+        problem: InputProblem,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum InputProblem {
+    Empty,
+    LoginRequired,
+    NotYetUnlocked,
+    Html,
+}
+
+impl std::fmt::Display for InputProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "input is empty"),
+            Self::LoginRequired => write!(f, "input is a request to log in"),
+            Self::NotYetUnlocked => write!(f, "input is a message that puzzle is not unlocked yet"),
+            Self::Html => write!(f, "input is an html page"),
+        }
+    }
+}
+
+impl std::fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Offline { day } => write!(f, "input for day {} is not cached in {} and offline mode is enabled", day, cached_input_path(*day).display()),
+            Self::Session(err) => err.fmt(f),
+            Self::Config(err) => err.fmt(f),
+            Self::CacheDirUnavailable(err) => write!(f, "failed to access input cache directory: {}", err),
+            Self::Request(err) => write!(f, "failed to download input: {}", err),
+            Self::Http { status } => write!(f, "advent of code website responded with status {}", status),
+            Self::PuzzleNotYetUnlocked { day } => write!(f, "puzzle for day {} is not unlocked yet", day),
+            Self::NotLoggedIn => write!(f, "session cookie has expired or is invalid, log in to advent of code website and update it with `aoc session set`"),
+            Self::InvalidInput(problem) => write!(f, "refusing to cache downloaded input: {}", problem),
+            Self::InvalidCachedInput { day, problem } => write!(
+                f,
+                "cached input for day {} is invalid ({}), run `aoc cache audit --evict` to remove it",
+                day,
+                problem
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DownloadError {}
+
+impl From<ConfigError> for DownloadError {
+    fn from(err: ConfigError) -> Self {
+        Self::Config(err)
+    }
+}
+
+impl From<reqwest::Error> for DownloadError {
+    fn from(err: reqwest::Error) -> Self {
+        Self::Request(err)
+    }
+}
+
+pub fn download_input(day: u32) -> Result<String, DownloadError> {
+    if let Some(input) = cached_input(day)? {
+        return Ok(input);
+    }
+
+    let config = config::get();
+    if config.offline {
+        return Err(DownloadError::Offline { day });
+    }
+
+    let session = session::validated()?;
+    // validating the session downloads day 1 input and caches it
+    if let Some(input) = cached_input(day)? {
+        return Ok(input);
+    }
+
+    eprintln!("downloading data from advent of code website");
+    let data = fetch_input(http::client()?, &session, day)?;
+
+    cache_input(config, day, &data)?;
+
+    Ok(data)
+}
+
+pub fn cache_input(config: &Config, day: u32, input: &str) -> Result<(), DownloadError> {
+    let cached_path = cached_input_path_in(&config.year_cache_dir(), day);
+    if let Some(parent) = cached_path.parent() {
+        create_dir_all(parent).map_err(DownloadError::CacheDirUnavailable)?;
+    }
+    write(cached_path, input).map_err(DownloadError::CacheDirUnavailable)
+}
+
+pub fn fetch_input(client: &HttpClient, session: &str, day: u32) -> Result<String, DownloadError> {
+    let HttpResponse { status, body } = client.get(&format!("{}/input", client.config().day_url(day)), Some(session))?;
+
+    let problem = validate_input(&body).err();
+    match (status, problem) {
+        (404, _) | (_, Some(InputProblem::NotYetUnlocked)) => Err(DownloadError::PuzzleNotYetUnlocked { day }),
+        (400, _) | (_, Some(InputProblem::LoginRequired)) => Err(DownloadError::NotLoggedIn),
+        (200, None) => Ok(body),
+        (200, Some(problem)) => Err(DownloadError::InvalidInput(problem)),
+        (status, _) => Err(DownloadError::Http { status }),
     }
+}
 
-    println!("downloading data from advent of code website");
-    let client = reqwest::blocking::Client::new();
-    let data = client.get(format!("https://adventofcode.com/2024/day/{}/input", day))
-        .header("cookie", format!("session={}", var("SESSION_COOKIE").unwrap()))
-        .send()
-        .unwrap()
-        .text()
-        .unwrap();
+// detects error pages that advent of code website may return instead of puzzle input
+pub fn validate_input(input: &str) -> Result<(), InputProblem> {
+    let trimmed = input.trim_start().to_lowercase();
+
+    if trimmed.is_empty() {
+        Err(InputProblem::Empty)
+    } else if input.contains("Puzzle inputs differ by user") || input.contains("Please log in") {
+        Err(InputProblem::LoginRequired)
+    } else if input.contains("before it unlocks") {
+        Err(InputProblem::NotYetUnlocked)
+    } else if trimmed.starts_with("<!doctype") || trimmed.starts_with("<html") || trimmed.contains("<body") {
+        Err(InputProblem::Html)
+    } else {
+        Ok(())
+    }
+}
 
-    write(cached_path, data.clone()).unwrap();
+pub fn cached_input(day: u32) -> Result<Option<String>, DownloadError> {
+    migrate_legacy_input(config::get(), day).map_err(DownloadError::CacheDirUnavailable)?;
 
-    data
+    let cached_path = cached_input_path(day);
+    if !exists(&cached_path).map_err(DownloadError::CacheDirUnavailable)? {
+        return Ok(None);
+    }
+
+    let input = read_to_string(cached_path).map_err(DownloadError::CacheDirUnavailable)?;
+    validate_input(&input).map_err(|problem| DownloadError::InvalidCachedInput { day, problem })?;
+
+    Ok(Some(input))
+}
+
+fn cached_input_path(day: u32) -> PathBuf {
+    cached_input_path_in(&config::get().year_cache_dir(), day)
+}
+
+fn cached_input_path_in(cache_dir: &Path, day: u32) -> PathBuf {
+    cache_dir.join(format!("day_{}_input.txt", day))
+}
+
+// inputs used to be cached directly in cache dir, before it was split by year. All of them are 2024 inputs.
+const LEGACY_CACHE_YEAR: u32 = 2024;
+
+// moves input cached in the legacy location to the directory of its year, so that it is not downloaded again
+fn migrate_legacy_input(config: &Config, day: u32) -> io::Result<()> {
+    if config.year != LEGACY_CACHE_YEAR {
+        return Ok(());
+    }
+
+    let legacy_path = cached_input_path_in(&config.cache_dir, day);
+    let cached_path = cached_input_path_in(&config.year_cache_dir(), day);
+    if !exists(&legacy_path)? || exists(&cached_path)? {
+        return Ok(());
+    }
+
+    if let Some(parent) = cached_path.parent() {
+        create_dir_all(parent)?;
+    }
+    rename(legacy_path, cached_path)
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+    pub day: u32,
+    pub problem: Option<InputProblem>,
+    pub evicted: bool,
+}
+
+pub fn audit_cache(evict: bool) -> io::Result<Vec<AuditEntry>> {
+    let config = config::get();
+    for day in 1..=TOTAL_DAYS {
+        migrate_legacy_input(config, day)?;
+    }
+    audit_cache_dir(&config.year_cache_dir(), evict)
+}
+
+// validates every cached input, optionally removing the invalid ones
+fn audit_cache_dir(cache_dir: &Path, evict: bool) -> io::Result<Vec<AuditEntry>> {
+    let mut entries = Vec::new();
+
+    for day in 1..=TOTAL_DAYS {
+        let path = cached_input_path_in(cache_dir, day);
+        if !exists(&path)? {
+            continue;
+        }
+
+        let problem = validate_input(&read_to_string(&path)?).err();
+        let evicted = evict && problem.is_some();
+        if evicted {
+            remove_file(&path)?;
+        }
+
+        entries.push(AuditEntry {
+            day,
+            problem,
+            evicted,
+        });
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        crate::{
+            config::Config,
+            http::{test_client, test_client_with},
+            test_server::TestServer,
+        },
+        super::*,
+    };
+
+    #[test]
+    fn test_offline_cache_miss() {
+        assert_eq!(
+            download_input(25).unwrap_err().to_string(),
+            "input for day 25 is not cached in data/2024/day_25_input.txt and offline mode is enabled"
+        );
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let server = TestServer::start(vec![(200, "1 2 3\n")]);
+        assert_eq!(fetch_input(&test_client(&server.base_url), "test-session", 11).unwrap(), "1 2 3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2024/day/11/input");
+        assert_eq!(requests[0].header("cookie"), Some("session=test-session"));
+    }
+
+    #[test]
+    fn test_fetch_input_other_year() {
+        let server = TestServer::start(vec![(200, "1721\n979\n")]);
+        let client = test_client_with(Config {
+            year: 2020,
+            base_url: server.base_url.clone(),
+            ..Config::default()
+        });
+        assert_eq!(fetch_input(&client, "test-session", 1).unwrap(), "1721\n979\n");
+        assert_eq!(server.requests()[0].path, "/2020/day/1/input");
+    }
+
+    #[test]
+    fn test_fetch_input_not_unlocked() {
+        let server = TestServer::start(vec![(404, "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n")]);
+        assert!(matches!(fetch_input(&test_client(&server.base_url), "test-session", 25), Err(DownloadError::PuzzleNotYetUnlocked { day: 25 })));
+    }
+
+    #[test]
+    fn test_fetch_input_not_logged_in() {
+        let server = TestServer::start(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")]);
+        assert!(matches!(fetch_input(&test_client(&server.base_url), "expired", 1), Err(DownloadError::NotLoggedIn)));
+    }
+
+    #[test]
+    fn test_fetch_input_login_page_with_ok_status() {
+        let server = TestServer::start(vec![(200, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")]);
+        assert!(matches!(fetch_input(&test_client(&server.base_url), "expired", 1), Err(DownloadError::NotLoggedIn)));
+    }
+
+    #[test]
+    fn test_fetch_input_html_page() {
+        let server = TestServer::start(vec![(200, "<!DOCTYPE html>\n<html><body>maintenance</body></html>")]);
+        assert!(matches!(fetch_input(&test_client(&server.base_url), "test-session", 1), Err(DownloadError::InvalidInput(InputProblem::Html))));
+    }
+
+    #[test]
+    fn test_validate_input() {
+        assert_eq!(validate_input("3   4\n4   3\n"), Ok(()));
+        assert_eq!(validate_input("\n"), Err(InputProblem::Empty));
+        assert_eq!(validate_input("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"), Err(InputProblem::LoginRequired));
+        assert_eq!(validate_input("Please don't repeatedly request this endpoint before it unlocks!"), Err(InputProblem::NotYetUnlocked));
+        assert_eq!(validate_input("<html><head></head><body></body></html>"), Err(InputProblem::Html));
+        // day 3 input contains '<' and '>' characters, but is not html
+        assert_eq!(validate_input("mul(2,4)&<do()>don't()_mul(5,5)"), Ok(()));
+    }
+
+    #[test]
+    fn test_audit_cache() {
+        let cache_dir = std::env::temp_dir().join(format!("aoc-cache-audit-{}", std::process::id()));
+        create_dir_all(&cache_dir).unwrap();
+        write(cached_input_path_in(&cache_dir, 1), "3   4\n").unwrap();
+        write(cached_input_path_in(&cache_dir, 2), "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n").unwrap();
+
+        assert_eq!(
+            audit_cache_dir(&cache_dir, false).unwrap(),
+            vec![
+                AuditEntry { day: 1, problem: None, evicted: false },
+                AuditEntry { day: 2, problem: Some(InputProblem::LoginRequired), evicted: false },
+            ]
+        );
+        assert_eq!(
+            audit_cache_dir(&cache_dir, true).unwrap()[1],
+            AuditEntry { day: 2, problem: Some(InputProblem::LoginRequired), evicted: true }
+        );
+        let remaining = audit_cache_dir(&cache_dir, false).unwrap();
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert_eq!(remaining, vec![AuditEntry { day: 1, problem: None, evicted: false }]);
+    }
+
+    #[test]
+    fn test_migrate_legacy_input() {
+        let config = Config {
+            cache_dir: std::env::temp_dir().join(format!("aoc-legacy-cache-{}", std::process::id())),
+            ..Config::default()
+        };
+        create_dir_all(&config.cache_dir).unwrap();
+        write(cached_input_path_in(&config.cache_dir, 1), "3   4\n").unwrap();
+
+        migrate_legacy_input(&config, 1).unwrap();
+        migrate_legacy_input(&config, 2).unwrap();
+        let migrated = read_to_string(cached_input_path_in(&config.year_cache_dir(), 1)).unwrap();
+        let legacy_left = exists(cached_input_path_in(&config.cache_dir, 1)).unwrap();
+        let other_year = Config { year: 2023, ..config.clone() };
+        write(cached_input_path_in(&config.cache_dir, 3), "1\n").unwrap();
+        migrate_legacy_input(&other_year, 3).unwrap();
+        let other_year_migrated = exists(cached_input_path_in(&other_year.year_cache_dir(), 3)).unwrap();
+        std::fs::remove_dir_all(&config.cache_dir).unwrap();
+
+        assert_eq!(migrated, "3   4\n");
+        assert!(!legacy_left);
+        assert!(!other_year_migrated);
+    }
+
+    #[test]
+    fn test_fetch_input_server_error() {
+        let server = TestServer::start(vec![(500, "Internal Server Error")]);
+        assert!(matches!(fetch_input(&test_client(&server.base_url), "test-session", 1), Err(DownloadError::Http { status: 500 })));
+    }
 }